@@ -1,6 +1,10 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
-use halo2curves::{bn256::*, ff::Field, ff_ext::Legendre};
-use rand::SeedableRng;
+use halo2curves::{
+    bn256::*,
+    ff::{Field, FromUniformBytes},
+    ff_ext::Legendre,
+};
+use rand::{RngCore, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
 pub fn bench_bn256_field(c: &mut Criterion) {
@@ -45,7 +49,78 @@ pub fn bench_bn256_field(c: &mut Criterion) {
     group.bench_function("bn256_fq_legendre", |bencher| {
         bencher.iter(|| black_box(&a).legendre())
     });
+    group.bench_function("bn256_fq_mul_add", |bencher| {
+        bencher.iter(|| black_box(&a).mul_add(black_box(&b), black_box(&a)))
+    });
+
+    // 254-bit exponent
+    let exp = [
+        rng.next_u64(),
+        rng.next_u64(),
+        rng.next_u64(),
+        rng.next_u64() >> 2,
+    ];
+
+    group.bench_function("bn256_fq_pow", |bencher| {
+        bencher.iter(|| black_box(&a).pow(black_box(exp)))
+    });
+    for window_bits in [4, 5] {
+        group.bench_function(
+            format!("bn256_fq_pow_fixed_window_{}", window_bits),
+            |bencher| bencher.iter(|| black_box(&a).pow_fixed_window(black_box(&exp), window_bits)),
+        );
+    }
+
+    // Horner evaluation of a degree 255 polynomial
+    let coeffs = (0..256).map(|_| Fr::random(&mut rng)).collect::<Vec<_>>();
+    let x = Fr::random(&mut rng);
+
+    group.bench_function("bn256_fr_horner_mul_then_add", |bencher| {
+        bencher.iter(|| {
+            coeffs
+                .iter()
+                .rev()
+                .fold(Fr::zero(), |acc, coeff| acc * black_box(&x) + coeff)
+        })
+    });
+    group.bench_function("bn256_fr_horner_mul_add", |bencher| {
+        bencher.iter(|| {
+            coeffs
+                .iter()
+                .rev()
+                .fold(Fr::zero(), |acc, coeff| acc.mul_add(black_box(&x), coeff))
+        })
+    });
+}
+
+pub fn bench_bn256_from_uniform_bytes(c: &mut Criterion) {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let n = 1 << 16;
+    let mut inputs = vec![[0u8; 64]; n];
+    for input in inputs.iter_mut() {
+        rng.fill_bytes(input);
+    }
+
+    let mut group = c.benchmark_group("BN256 Fr from_uniform_bytes");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(n as u64));
+
+    group.bench_function("elementwise", |bencher| {
+        bencher.iter(|| {
+            black_box(&inputs)
+                .iter()
+                .map(Fr::from_uniform_bytes)
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("parallel", |bencher| {
+        bencher.iter(|| Fr::from_uniform_bytes_batch(black_box(&inputs)))
+    });
 }
 
-criterion_group!(benches, bench_bn256_field);
+criterion_group!(benches, bench_bn256_field, bench_bn256_from_uniform_bytes);
 criterion_main!(benches);
@@ -1,6 +1,7 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use ff::Field;
-use group::prime::PrimeCurveAffine;
+use group::{prime::PrimeCurveAffine, Curve, Group};
+use halo2curves::bn256::{Fr, G1Affine, G1, G2};
 use halo2curves::secp256k1::Secp256k1;
 use pasta_curves::arithmetic::CurveExt;
 use rand_core::OsRng;
@@ -48,5 +49,81 @@ fn criterion_benchmark<G: CurveExt>(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, criterion_benchmark<Secp256k1>);
+fn bench_bn256_g1_glv(c: &mut Criterion) {
+    let p = G1::random(OsRng);
+    let s = Fr::random(OsRng);
+
+    c.bench_function("bn256 G1 scalar multiplication", move |b| {
+        b.iter(|| black_box(p) * black_box(s))
+    });
+    c.bench_function("bn256 G1 GLV scalar multiplication", move |b| {
+        b.iter(|| black_box(p).mul_glv(black_box(&s)))
+    });
+}
+
+fn bench_bn256_g2_gls(c: &mut Criterion) {
+    let p = G2::random(OsRng);
+    let s = Fr::random(OsRng);
+
+    c.bench_function("bn256 G2 scalar multiplication", move |b| {
+        b.iter(|| black_box(p) * black_box(s))
+    });
+    c.bench_function("bn256 G2 GLS scalar multiplication", move |b| {
+        b.iter(|| black_box(p).mul_gls(black_box(&s)))
+    });
+}
+
+fn bench_bn256_wnaf(c: &mut Criterion) {
+    let p = G1::random(OsRng);
+    let q = G2::random(OsRng);
+    let s = Fr::random(OsRng);
+
+    for window in [4, 5] {
+        c.bench_function(
+            &format!("bn256 G1 wNAF scalar multiplication w={window}"),
+            move |b| b.iter(|| black_box(p).mul_wnaf(black_box(&s), window)),
+        );
+        c.bench_function(
+            &format!("bn256 G2 wNAF scalar multiplication w={window}"),
+            move |b| b.iter(|| black_box(q).mul_wnaf(black_box(&s), window)),
+        );
+    }
+}
+
+fn bench_bn256_g1_batch_normalize(c: &mut Criterion) {
+    const N: usize = 1 << 16;
+    let p = G1::random(OsRng);
+    // a chain of additions leaves z-coordinates other than one, as after an MSM
+    let v = std::iter::successors(Some(p), |acc| Some(acc + p))
+        .take(N)
+        .collect::<Vec<_>>();
+    let mut q = vec![G1Affine::identity(); N];
+
+    let mut group = c.benchmark_group("bn256 G1 to affine");
+    group.sample_size(10);
+    group.bench_function(format!("batch_normalize n={N}"), |b| {
+        b.iter(|| {
+            G1::batch_normalize(black_box(&v), black_box(&mut q));
+            black_box(&q)[0]
+        })
+    });
+    group.bench_function(format!("to_affine n={N}"), |b| {
+        b.iter(|| {
+            black_box(&v)
+                .iter()
+                .map(|p| p.to_affine())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    criterion_benchmark<Secp256k1>,
+    bench_bn256_g1_glv,
+    bench_bn256_g2_gls,
+    bench_bn256_wnaf,
+    bench_bn256_g1_batch_normalize
+);
 criterion_main!(benches);
@@ -1,5 +1,6 @@
 //! This benchmarks Multi Scalar Multiplication (MSM).
-//! It measures `G1` from the BN256 curve.
+//! It measures `G1` from the BN256 curve, and secp256k1 with and without
+//! its GLV endomorphism.
 //!
 //! To run this benchmark:
 //!
@@ -7,16 +8,19 @@
 //!
 //! Caveat:  The multicore benchmark assumes:
 //!     1. a multi-core system
-//!     2. that the `multicore` feature is enabled.  It is by default.
+//!     2. that the `parallel` feature is enabled.  It is by default.
 
 #[macro_use]
 extern crate criterion;
 
 use criterion::{BenchmarkId, Criterion};
-use ff::Field;
+use ff::{Field, PrimeField};
 use group::prime::PrimeCurveAffine;
 use halo2curves::bn256::{Fr as Scalar, G1Affine as Point};
-use halo2curves::msm::{best_multiexp, multiexp_serial};
+use halo2curves::msm::{
+    best_multiexp, best_multiexp_glv, best_window, msm_with_window, multiexp_serial,
+};
+use halo2curves::secp256k1::{msm_glv, Fq as Secp256k1Scalar, Secp256k1Affine};
 use rand_core::SeedableRng;
 use rand_xorshift::XorShiftRng;
 use rayon::current_thread_index;
@@ -26,6 +30,9 @@ use std::time::SystemTime;
 const SAMPLE_SIZE: usize = 10;
 const SINGLECORE_RANGE: [u8; 6] = [3, 8, 10, 12, 14, 16];
 const MULTICORE_RANGE: [u8; 9] = [3, 8, 10, 12, 14, 16, 18, 20, 22];
+const GLV_RANGE: [u8; 3] = [14, 16, 18];
+const WINDOW_RANGE: [u8; 2] = [18, 20];
+const SECP256K1_GLV_RANGE: [u8; 2] = [14, 16];
 const SEED: [u8; 16] = [
     0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc, 0xe5,
 ];
@@ -109,8 +116,83 @@ fn msm(c: &mut Criterion) {
             })
             .sample_size(SAMPLE_SIZE);
     }
+    // 16-bit scalars leave most buckets of every window empty
+    let small_coeffs: Vec<_> = coeffs
+        .iter()
+        .map(|c| {
+            Scalar::from(u64::from(u16::from_le_bytes([
+                c.to_repr()[0],
+                c.to_repr()[1],
+            ])))
+        })
+        .collect();
+    for k in SINGLECORE_RANGE {
+        group
+            .bench_function(BenchmarkId::new("singlecore_small_scalars", k), |b| {
+                assert!(k < 64);
+                let n: usize = 1 << k;
+                let mut acc = Point::identity().into();
+                b.iter(|| multiexp_serial(&small_coeffs[..n], &bases[..n], &mut acc));
+            })
+            .sample_size(SAMPLE_SIZE);
+    }
+    for k in GLV_RANGE {
+        group
+            .bench_function(BenchmarkId::new("multicore_glv", k), |b| {
+                assert!(k < 64);
+                let n: usize = 1 << k;
+                b.iter(|| {
+                    best_multiexp_glv(&coeffs[..n], &bases[..n]);
+                })
+            })
+            .sample_size(SAMPLE_SIZE);
+    }
+    // fixed windows around the heuristic
+    for k in WINDOW_RANGE {
+        let n: usize = 1 << k;
+        let best = best_window(n);
+        for window in best - 3..=best + 3 {
+            group
+                .bench_function(
+                    BenchmarkId::new(format!("multicore_window_{window}"), k),
+                    |b| {
+                        b.iter(|| {
+                            msm_with_window(&coeffs[..n], &bases[..n], window);
+                        })
+                    },
+                )
+                .sample_size(SAMPLE_SIZE);
+        }
+    }
+    group.finish();
+}
+
+fn msm_secp256k1(c: &mut Criterion) {
+    let mut group = c.benchmark_group("msm_secp256k1");
+    let max_k = *SECP256K1_GLV_RANGE.iter().max().unwrap();
+    let mut rng = XorShiftRng::from_seed(SEED);
+    let coeffs: Vec<_> = (0..1 << max_k)
+        .map(|_| Secp256k1Scalar::random(&mut rng))
+        .collect();
+    let bases: Vec<_> = (0..1 << max_k)
+        .map(|_| Secp256k1Affine::random(&mut rng))
+        .collect();
+
+    for k in SECP256K1_GLV_RANGE {
+        let n: usize = 1 << k;
+        group
+            .bench_function(BenchmarkId::new("multicore", k), |b| {
+                b.iter(|| best_multiexp(&coeffs[..n], &bases[..n]))
+            })
+            .sample_size(SAMPLE_SIZE);
+        group
+            .bench_function(BenchmarkId::new("multicore_glv", k), |b| {
+                b.iter(|| msm_glv(&coeffs[..n], &bases[..n]))
+            })
+            .sample_size(SAMPLE_SIZE);
+    }
     group.finish();
 }
 
-criterion_group!(benches, msm);
+criterion_group!(benches, msm, msm_secp256k1);
 criterion_main!(benches);
@@ -0,0 +1,31 @@
+//! Compile check that `halo2curves` builds without `std`, with field and
+//! curve arithmetic, multi-exponentiation and raw serialization. CI builds
+//! it for a bare-metal target, where any use of `std` fails to link.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use halo2curves::bn256::{Fr, G1Affine, G1};
+use halo2curves::group::Curve;
+use halo2curves::msm::best_multiexp;
+use halo2curves::serde::SerdeObject;
+
+pub fn field_arithmetic(a: Fr, b: Fr) -> Option<Fr> {
+    let c = (a + b) * a.square() - b.double();
+    Option::from(c.invert())
+}
+
+pub fn msm(coeffs: &[Fr]) -> G1 {
+    let bases: Vec<G1Affine> = (0..coeffs.len() as u64)
+        .map(|i| (G1::generator() * Fr::from(i + 1)).to_affine())
+        .collect();
+    best_multiexp(coeffs, &bases)
+}
+
+pub fn raw_roundtrip(a: Fr, p: G1Affine) -> Option<(Fr, G1Affine)> {
+    let a = Fr::from_raw_bytes(&a.to_raw_bytes())?;
+    let p = G1Affine::from_raw_bytes(&p.to_raw_bytes())?;
+    Some((a, p))
+}
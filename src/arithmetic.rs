@@ -17,6 +17,18 @@ pub trait CurveEndo: CurveExt {
     fn decompose_scalar(e: &Self::ScalarExt) -> (u128, bool, u128, bool);
 }
 
+/// Curves that can undo their cofactor clearing.
+pub trait CurveCofactor: CurveExt {
+    /// Multiplies by the inverse of the cofactor modulo the prime subgroup
+    /// order, so that `clear_cofactor` maps the result back to `self` for
+    /// points of the prime-order subgroup.
+    ///
+    /// For cofactor one this is the identity map. Otherwise the result is
+    /// only one representative of the preimages under cofactor clearing,
+    /// not a true inverse of it on the whole curve.
+    fn mul_by_cofactor_inv(&self) -> Self;
+}
+
 /// Compute a + b + carry, returning the result and the new carry over.
 #[inline(always)]
 pub(crate) const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
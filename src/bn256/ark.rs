@@ -0,0 +1,143 @@
+//! Conversions between the BN254 types of this crate and those of `ark_bn254`.
+//!
+//! Both libraries use the same moduli, the same quadratic extension
+//! $F_{q^2} = F_q[u] / (u^2 + 1)$ with `c0 + c1 * u`, and the same affine
+//! coordinates for `G1` and its twist `G2`. Field elements are converted
+//! through their canonical little-endian bytes, and points coordinate by
+//! coordinate, with the identity mapped to the point at infinity.
+//!
+//! The compressed point encodings of the two libraries are *not*
+//! interchangeable: they place the identity and sign flags differently, so
+//! points must be converted with these impls rather than by reusing bytes.
+
+use ark_ff::{BigInteger, PrimeField as _};
+use ff::PrimeField;
+use group::prime::PrimeCurveAffine;
+
+use super::{Fq, Fq2, Fr, G1Affine, G2Affine};
+
+macro_rules! impl_ark_field {
+    ($field:ident) => {
+        impl From<ark_bn254::$field> for $field {
+            fn from(e: ark_bn254::$field) -> $field {
+                let bytes = e.into_bigint().to_bytes_le();
+                $field::from_repr(bytes.try_into().unwrap()).unwrap()
+            }
+        }
+
+        impl From<$field> for ark_bn254::$field {
+            fn from(e: $field) -> ark_bn254::$field {
+                // canonical, so the reduction never changes the value
+                ark_bn254::$field::from_le_bytes_mod_order(&e.to_repr())
+            }
+        }
+    };
+}
+
+impl_ark_field!(Fq);
+impl_ark_field!(Fr);
+
+impl From<ark_bn254::Fq2> for Fq2 {
+    fn from(e: ark_bn254::Fq2) -> Fq2 {
+        Fq2::new(e.c0.into(), e.c1.into())
+    }
+}
+
+impl From<Fq2> for ark_bn254::Fq2 {
+    fn from(e: Fq2) -> ark_bn254::Fq2 {
+        ark_bn254::Fq2::new(e.c0.into(), e.c1.into())
+    }
+}
+
+macro_rules! impl_ark_affine {
+    ($affine:ident) => {
+        impl From<ark_bn254::$affine> for $affine {
+            fn from(p: ark_bn254::$affine) -> $affine {
+                if p.infinity {
+                    $affine::identity()
+                } else {
+                    $affine {
+                        x: p.x.into(),
+                        y: p.y.into(),
+                    }
+                }
+            }
+        }
+
+        impl From<$affine> for ark_bn254::$affine {
+            fn from(p: $affine) -> ark_bn254::$affine {
+                if bool::from(p.is_identity()) {
+                    ark_bn254::$affine::identity()
+                } else {
+                    ark_bn254::$affine::new_unchecked(p.x.into(), p.y.into())
+                }
+            }
+        }
+    };
+}
+
+impl_ark_affine!(G1Affine);
+impl_ark_affine!(G2Affine);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::{G1, G2};
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_ark_field_roundtrip() {
+        let edge = [Fr::zero(), Fr::one(), -Fr::one()];
+        for e in edge.into_iter().chain((0..100).map(|_| Fr::random(OsRng))) {
+            let ark: ark_bn254::Fr = e.into();
+            assert_eq!(Fr::from(ark), e);
+        }
+        assert_eq!(ark_bn254::Fr::from(Fr::from(7)), ark_bn254::Fr::from(7u64));
+
+        let edge = [Fq::zero(), Fq::one(), -Fq::one()];
+        for e in edge.into_iter().chain((0..100).map(|_| Fq::random(OsRng))) {
+            let ark: ark_bn254::Fq = e.into();
+            assert_eq!(Fq::from(ark), e);
+        }
+        assert_eq!(ark_bn254::Fq::from(Fq::from(7)), ark_bn254::Fq::from(7u64));
+
+        // arithmetic agrees
+        let (a, b) = (Fr::random(OsRng), Fr::random(OsRng));
+        let (ark_a, ark_b): (ark_bn254::Fr, ark_bn254::Fr) = (a.into(), b.into());
+        assert_eq!(Fr::from(ark_a * ark_b), a * b);
+        let (a, b) = (Fq2::random(OsRng), Fq2::random(OsRng));
+        let (ark_a, ark_b): (ark_bn254::Fq2, ark_bn254::Fq2) = (a.into(), b.into());
+        assert_eq!(Fq2::from(ark_a * ark_b), a * b);
+    }
+
+    #[test]
+    fn test_ark_point_roundtrip() {
+        let generator: ark_bn254::G1Affine = G1Affine::generator().into();
+        assert_eq!(generator.x, ark_bn254::g1::G1_GENERATOR_X);
+        assert_eq!(generator.y, ark_bn254::g1::G1_GENERATOR_Y);
+        let generator: ark_bn254::G2Affine = G2Affine::generator().into();
+        assert_eq!(generator.x, ark_bn254::g2::G2_GENERATOR_X);
+        assert_eq!(generator.y, ark_bn254::g2::G2_GENERATOR_Y);
+
+        for _ in 0..20 {
+            let p = G1::random(OsRng).to_affine();
+            let ark: ark_bn254::G1Affine = p.into();
+            assert!(ark.is_on_curve() && ark.is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(G1Affine::from(ark), p);
+
+            let q = G2::random(OsRng).to_affine();
+            let ark: ark_bn254::G2Affine = q.into();
+            assert!(ark.is_on_curve() && ark.is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(G2Affine::from(ark), q);
+        }
+
+        let identity: ark_bn254::G1Affine = G1Affine::identity().into();
+        assert!(identity.infinity);
+        assert_eq!(G1Affine::from(identity), G1Affine::identity());
+        let identity: ark_bn254::G2Affine = G2Affine::identity().into();
+        assert!(identity.infinity);
+        assert_eq!(G2Affine::from(identity), G2Affine::identity());
+    }
+}
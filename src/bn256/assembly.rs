@@ -4,7 +4,7 @@ macro_rules! field_arithmetic_asm {
         $modulus:ident,
         $inv:ident
     ) => {
-        use std::arch::asm;
+        use core::arch::asm;
 
         impl $field {
             /// Doubles this field element.
@@ -1,29 +1,34 @@
 use crate::arithmetic::mul_512;
 use crate::arithmetic::sbb;
-use crate::arithmetic::CurveEndo;
 use crate::arithmetic::EndoParameters;
+use crate::arithmetic::{CurveCofactor, CurveEndo};
 use crate::bn256::Fq;
 use crate::bn256::Fq2;
 use crate::bn256::Fr;
+use crate::bn256::{BN_X, FROBENIUS_COEFF_FQ6_C1, XI_TO_Q_MINUS_1_OVER_2};
 use crate::endo;
 use crate::ff::WithSmallOrderMulGroup;
-use crate::ff::{Field, PrimeField};
+use crate::ff::{BatchInvert, Field, PrimeField};
+use crate::ff_ext::Legendre;
 use crate::group::Curve;
 use crate::group::{cofactor::CofactorGroup, prime::PrimeCurveAffine, Group, GroupEncoding};
-use crate::hash_to_curve::svdw_hash_to_curve;
+#[cfg(feature = "sha2")]
+use crate::hash_to_curve::{encode_to_curve_sha256, hash_to_curve_sha256};
+use crate::hash_to_curve::{svdw_hash_to_curve, svdw_map_to_curve, svdw_precomputed_constants};
 use crate::{
     impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
     impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_sub_binop_specify_output,
     new_curve_impl,
 };
 use crate::{Coordinates, CurveAffine, CurveExt};
+use alloc::{vec, vec::Vec};
 use core::cmp;
+use core::convert::TryInto;
 use core::fmt::Debug;
 use core::iter::Sum;
 use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
-use std::convert::TryInto;
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
@@ -143,28 +148,38 @@ impl group::cofactor::CofactorGroup for G1 {
     }
 }
 
+impl CurveCofactor for G1 {
+    fn mul_by_cofactor_inv(&self) -> Self {
+        *self
+    }
+}
+
+/// Inverse modulo the group order of the scalar by which `G2::clear_cofactor`
+/// multiplies points of the prime order subgroup, $x + 3xp + xp^2 + p^3$.
+const G2_COFACTOR_INV: Fr = Fr::from_raw([
+    0x8236b51f1ef338ef,
+    0xc28f069fbb966e3f,
+    0x5e6dd9e7e0acccb0,
+    0x30644e72e131a029,
+]);
+
+impl CurveCofactor for G2 {
+    fn mul_by_cofactor_inv(&self) -> Self {
+        self * G2_COFACTOR_INV
+    }
+}
+
 impl CofactorGroup for G2 {
     type Subgroup = G2;
 
+    /// Maps `self` into the prime order subgroup with the endomorphism $\psi$,
+    /// computing $[x]P + \psi([3x]P) + \psi^2([x]P) + \psi^3(P)$ instead of
+    /// multiplying by the 254-bit cofactor.
+    ///
+    /// Reference: <https://eprint.iacr.org/2008/530>, section 6.1
     fn clear_cofactor(&self) -> Self {
-        // "0x30644e72e131a029b85045b68181585e06ceecda572a2489345f2299c0f9fa8d"
-        let e: [u8; 32] = [
-            0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
-            0x58, 0x5e, 0x06, 0xce, 0xec, 0xda, 0x57, 0x2a, 0x24, 0x89, 0x34, 0x5f, 0x22, 0x99,
-            0xc0, 0xf9, 0xfa, 0x8d,
-        ];
-
-        // self * COFACTOR_G2
-        let mut acc = G2::identity();
-        for bit in e
-            .iter()
-            .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
-            .skip(1)
-        {
-            acc = acc.double();
-            acc = G2::conditional_select(&acc, &(acc + self), bit);
-        }
-        acc
+        let xp = self.mul_by_x();
+        xp + (xp.double() + xp).psi() + xp.psi().psi() + self.psi().psi().psi()
     }
 
     fn into_subgroup(self) -> CtOption<Self::Subgroup> {
@@ -194,8 +209,662 @@ impl CofactorGroup for G2 {
     }
 }
 
+impl G2 {
+    /// The untwist-Frobenius-twist endomorphism $\psi$, which acts on the
+    /// prime order subgroup as multiplication by $p$.
+    pub(crate) fn psi(&self) -> G2 {
+        let mut x = self.x;
+        x.conjugate();
+        x.mul_assign(&FROBENIUS_COEFF_FQ6_C1[1]);
+        let mut y = self.y;
+        y.conjugate();
+        y.mul_assign(&XI_TO_Q_MINUS_1_OVER_2);
+        let mut z = self.z;
+        z.conjugate();
+        G2 { x, y, z }
+    }
+
+    /// Multiplies by the BN parameter $x$, which is public.
+    fn mul_by_x(&self) -> G2 {
+        let mut acc = G2::identity();
+        for i in (0..64 - BN_X.leading_zeros()).rev() {
+            acc = acc.double();
+            if (BN_X >> i) & 1 == 1 {
+                acc += self;
+            }
+        }
+        acc
+    }
+}
+
+/// Short vectors $b$ with $\sum_j b_j p^j \equiv 0 \pmod r$, spanning an index 3
+/// sublattice of the scalars that $\psi$ maps to zero.
+///
+/// Reference: <https://eprint.iacr.org/2008/117>, example 5
+const GLS_BASIS: [[i128; 4]; 4] = {
+    let x = BN_X as i128;
+    [
+        [x + 1, x, x, -2 * x],
+        [2 * x + 1, -x, -(x + 1), -x],
+        [2 * x, 2 * x + 1, 2 * x + 1, 2 * x + 1],
+        [x - 1, 4 * x + 2, -2 * x + 1, x - 1],
+    ]
+};
+
+/// $\lfloor |a_i| 2^{256} / |\det| \rfloor$ and the sign of $a_i$, where
+/// $a_i / \det$ is the first row of the inverse of `GLS_BASIS`.
+const GLS_ROUNDING: [([u64; 4], bool); 4] = [
+    ([0xd0cb46fd51906254, 0xc444fab18d269b9d, 0, 0], false),
+    (
+        [
+            0x001378f5ee78976d,
+            0x22df9f942d7d77c7,
+            0x3d00631561b25729,
+            0x1,
+        ],
+        false,
+    ),
+    (
+        [
+            0x36510546a93478ab,
+            0x916fcfca16bebbe4,
+            0x9e80318ab0d92b94,
+            0,
+        ],
+        false,
+    ),
+    ([0xf7ae23ce89afae7c, 0xc444fab18d269b9a, 0, 0], true),
+];
+
+/// Upper bound on the bit length of the parts of `G2::decompose_gls`: each
+/// is less than $2 \sum_i |b_{ij}| \le 16x + 6 < 2^{67}$.
+const GLS_BITS: usize = 67;
+
+impl G2 {
+    /// Splits `scalar` into $(k_0, k_1, k_2, k_3)$ with
+    /// $\sum_j k_j p^j \equiv scalar \pmod r$ and $|k_j| < 2^{67}$, rounding
+    /// `(scalar, 0, 0, 0)` to a close vector of `GLS_BASIS`.
+    fn decompose_gls(scalar: &Fr) -> [i128; 4] {
+        let limbs: [u64; 4] = {
+            let repr = scalar.to_repr();
+            core::array::from_fn(|i| u64::from_le_bytes(repr[8 * i..8 * i + 8].try_into().unwrap()))
+        };
+
+        // The parts are small, so it suffices to compute them modulo 2^128
+        let mut parts = [
+            (limbs[0] as u128 | (limbs[1] as u128) << 64) as i128,
+            0,
+            0,
+            0,
+        ];
+        for (row, (g, neg)) in GLS_BASIS.iter().zip(GLS_ROUNDING.iter()) {
+            let c = mul_512(limbs, *g);
+            let c = (c[4] as u128 | (c[5] as u128) << 64) as i128;
+            let c = if *neg { c.wrapping_neg() } else { c };
+            for (part, b) in parts.iter_mut().zip(row.iter()) {
+                *part = part.wrapping_sub(c.wrapping_mul(*b));
+            }
+        }
+        parts
+    }
+
+    /// Multiplies this point by `scalar` using the GLS decomposition along
+    /// $\psi$, which acts as multiplication by $p$ on the prime order
+    /// subgroup.
+    ///
+    /// The scalar is split into four parts of at most 67 bits each, which
+    /// are then applied jointly, dividing the number of doublings by almost
+    /// four. `self` must be in the prime order subgroup.
+    pub fn mul_gls(&self, scalar: &Fr) -> G2 {
+        let parts = G2::decompose_gls(scalar);
+
+        let mut bases = [*self; 4];
+        for i in 1..4 {
+            bases[i] = bases[i - 1].psi();
+        }
+        for (base, part) in bases.iter_mut().zip(parts.iter()) {
+            base.conditional_negate(Choice::from((*part < 0) as u8));
+        }
+        let parts = parts.map(|part| part.unsigned_abs());
+
+        let mut table = [G2::identity(); 16];
+        for i in 1..16 {
+            table[i] = table[i & (i - 1)] + bases[i.trailing_zeros() as usize];
+        }
+
+        let mut acc = G2::identity();
+        for i in (0..GLS_BITS).rev() {
+            acc = acc.double();
+            let index = parts.iter().enumerate().fold(0u8, |index, (j, part)| {
+                index | (((part >> i) & 1) as u8) << j
+            });
+            let mut addend = G2::identity();
+            for (j, entry) in table.iter().enumerate() {
+                addend.conditional_assign(entry, (j as u8).ct_eq(&index));
+            }
+            acc += addend;
+        }
+        acc
+    }
+}
+
+impl G2Affine {
+    /// Returns whether `self` lies in the prime order subgroup, checking
+    /// $\psi(P) = [6x^2]P$ rather than multiplying by the group order.
+    ///
+    /// Reference: <https://eprint.iacr.org/2022/352>, section 4.3
+    pub fn is_in_correct_subgroup(&self) -> Choice {
+        let p = self.to_curve();
+        let x2p = p.mul_by_x().mul_by_x();
+        let six_x2p = (x2p.double() + x2p).double();
+        p.psi().ct_eq(&six_x2p)
+    }
+}
+
 impl G1 {
     const SVDW_Z: Fq = Fq::ONE;
+
+    /// Maps `u` to a point with the Shallue-van de Woestijne method, the
+    /// `map_to_curve` step of [`G1::hash_to_curve`].
+    ///
+    /// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-shallue-van-de-woestijne-met>
+    pub fn map_to_curve(u: Fq) -> G1 {
+        lazy_static::lazy_static! {
+            static ref SVDW_CONSTANTS: [Fq; 4] = svdw_precomputed_constants::<G1>(G1::SVDW_Z);
+        }
+        let [c1, c2, c3, c4] = *SVDW_CONSTANTS;
+        svdw_map_to_curve(u, c1, c2, c3, c4, G1::SVDW_Z)
+    }
+
+    /// Returns the `hash_to_curve` of the `BN254G1_XMD:SHA-256_SVDW_RO_` suite
+    /// under the domain separation tag `dst`.
+    ///
+    /// Unlike [`CurveExt::hash_to_curve`], which expands messages with
+    /// BLAKE2b, this agrees with other implementations of the suite, e.g.
+    /// gnark-crypto.
+    ///
+    /// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-encoding-byte-strings-to-el>
+    #[cfg(feature = "sha2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sha2")))]
+    pub fn hash_to_curve_sha256(dst: &[u8]) -> impl Fn(&[u8]) -> G1 + '_ {
+        move |message| hash_to_curve_sha256(dst, message, G1::map_to_curve)
+    }
+
+    /// Returns the `encode_to_curve` of the `BN254G1_XMD:SHA-256_SVDW_NU_`
+    /// suite under the domain separation tag `dst`.
+    ///
+    /// Its output distribution is not uniform, see
+    /// [`G1::hash_to_curve_sha256`] for a random oracle.
+    #[cfg(feature = "sha2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sha2")))]
+    pub fn encode_to_curve_sha256(dst: &[u8]) -> impl Fn(&[u8]) -> G1 + '_ {
+        move |message| encode_to_curve_sha256(dst, message, G1::map_to_curve)
+    }
+
+    /// Returns whether some point of the curve has `x` as its x-coordinate,
+    /// i.e. whether $x^3 + b$ is a square, without computing the square root.
+    pub fn x_is_valid(x: &Fq) -> Choice {
+        (x.square() * x + G1::b()).ct_quadratic_residue()
+    }
+
+    /// Multiplies this point by the plain integer given by `limbs` in
+    /// little-endian order, without converting it into an `Fr` first.
+    /// Limbs need not be reduced modulo the group order.
+    pub fn mul_limbs(&self, limbs: &[u64]) -> G1 {
+        let mut acc = G1::identity();
+        for limb in limbs.iter().rev() {
+            for i in (0..64).rev() {
+                acc = acc.double();
+                let bit = Choice::from(((limb >> i) & 1) as u8);
+                acc = G1::conditional_select(&acc, &(acc + self), bit);
+            }
+        }
+        acc
+    }
+
+    /// Multiplies this point by `scalar` using the GLV endomorphism
+    /// $(x, y) \mapsto (\beta x, y)$, which acts as multiplication by
+    /// `Fr::ZETA`.
+    ///
+    /// The scalar is split into two halves of about 128 bits each, which are
+    /// then applied jointly, halving the number of doublings.
+    pub fn mul_glv(&self, scalar: &Fr) -> G1 {
+        // scalar = ±k1 - ZETA * (±k2)
+        let (k1, k1_neg, k2, k2_neg) = G1::decompose_scalar(scalar);
+        let mut p1 = *self;
+        p1.conditional_negate(Choice::from(k1_neg as u8));
+        let mut p2 = self.endo();
+        p2.conditional_negate(Choice::from(!k2_neg as u8));
+
+        let table = [G1::identity(), p1, p2, p1 + p2];
+        let mut acc = G1::identity();
+        for i in (0..128).rev() {
+            acc = acc.double();
+            let index = (((k1 >> i) & 1) | (((k2 >> i) & 1) << 1)) as u8;
+            let mut addend = G1::identity();
+            for (j, entry) in table.iter().enumerate() {
+                addend.conditional_assign(entry, (j as u8).ct_eq(&index));
+            }
+            acc += addend;
+        }
+        acc
+    }
+
+    /// Multiplies this point by `scalar` split into the additive shares
+    /// `scalar - r` and `r`, for a fresh random `r` drawn from `rng` on every
+    /// call, as $[k - r]P + [r]P$.
+    ///
+    /// Neither multiplication processes the bits of `scalar` itself, so power
+    /// traces or injected faults from repeated calls with the same scalar
+    /// (DPA and fault attacks) see unrelated shares. This adds nothing against
+    /// timing attacks: it costs two constant time multiplications where
+    /// `self * scalar` costs one.
+    pub fn mul_blinded(&self, scalar: &Fr, rng: impl RngCore) -> G1 {
+        let r = Fr::random(rng);
+        self * (scalar - r) + self * r
+    }
+}
+
+impl G1 {
+    /// Precomputes the odd multiples $P, 3P, \ldots, (2^{w-1} - 1)P$ of this
+    /// point for width-$w$ NAF multiplication by several scalars.
+    ///
+    /// This function will panic if `window` is not in `2..=16`.
+    pub fn precompute_wnaf(&self, window: usize) -> WnafBase {
+        WnafBase::new(self, window)
+    }
+
+    /// Multiplies this point by `scalar` with a width-`window` NAF, walking
+    /// the digits over a table of the odd multiples $P, 3P, 5P, \ldots$.
+    /// Windows of 4 or 5 are the fastest for a single multiplication.
+    ///
+    /// **This operation is variable time with respect to the scalar.**
+    ///
+    /// This function will panic if `window` is not in `2..=16`.
+    pub fn mul_wnaf(&self, scalar: &Fr, window: usize) -> G1 {
+        self.precompute_wnaf(window).mul(scalar)
+    }
+}
+
+impl G2 {
+    /// Precomputes the odd multiples $P, 3P, \ldots, (2^{w-1} - 1)P$ of this
+    /// point for width-$w$ NAF multiplication by several scalars.
+    ///
+    /// This function will panic if `window` is not in `2..=16`.
+    pub fn precompute_wnaf(&self, window: usize) -> WnafBase<G2> {
+        WnafBase::new(self, window)
+    }
+
+    /// Multiplies this point by `scalar` with a width-`window` NAF, as
+    /// [`G1::mul_wnaf`].
+    ///
+    /// **This operation is variable time with respect to the scalar.**
+    ///
+    /// This function will panic if `window` is not in `2..=16`.
+    pub fn mul_wnaf(&self, scalar: &Fr, window: usize) -> G2 {
+        self.precompute_wnaf(window).mul(scalar)
+    }
+}
+
+/// Odd multiples of a fixed `G1` or `G2` point, in affine form, for variable
+/// time width-$w$ NAF scalar multiplication. See [`G1::precompute_wnaf`].
+#[derive(Clone, Debug)]
+pub struct WnafBase<C: CurveExt<ScalarExt = Fr> = G1> {
+    window: usize,
+    table: Vec<C::AffineExt>,
+}
+
+impl<C: CurveExt<ScalarExt = Fr>> WnafBase<C> {
+    fn new(point: &C, window: usize) -> Self {
+        assert!((2..=16).contains(&window));
+
+        let double = point.double();
+        let odd_multiples: Vec<C> = core::iter::successors(Some(*point), |p| Some(*p + double))
+            .take(1 << (window - 2))
+            .collect();
+        let mut table = vec![C::AffineExt::identity(); odd_multiples.len()];
+        C::batch_normalize(&odd_multiples, &mut table);
+
+        WnafBase { window, table }
+    }
+
+    /// Returns the width-$w$ NAF of `scalar`, least significant digit first.
+    /// Nonzero digits are odd, lie in $(-2^{w-1}, 2^{w-1})$, and are followed
+    /// by at least $w - 1$ zeros.
+    fn wnaf(&self, scalar: &Fr) -> Vec<i64> {
+        let repr = scalar.to_repr();
+        let mut k: [u64; 5] = [0; 5];
+        for (limb, bytes) in k.iter_mut().zip(repr.chunks(8)) {
+            *limb = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+
+        let width = 1u64 << self.window;
+        let mut digits = Vec::with_capacity(Fr::NUM_BITS as usize + 1);
+        while k.iter().any(|limb| *limb != 0) {
+            let digit = if k[0] & 1 == 1 {
+                let digit = (k[0] & (width - 1)) as i64;
+                let digit = if digit >= (width / 2) as i64 {
+                    digit - width as i64
+                } else {
+                    digit
+                };
+
+                // k -= digit, which clears the lowest `window` bits
+                let (k0, mut carry) = if digit >= 0 {
+                    (k[0] - digit as u64, false)
+                } else {
+                    k[0].overflowing_add(digit.unsigned_abs())
+                };
+                k[0] = k0;
+                for limb in k.iter_mut().skip(1) {
+                    if !carry {
+                        break;
+                    }
+                    (*limb, carry) = limb.overflowing_add(1);
+                }
+                digit
+            } else {
+                0
+            };
+            digits.push(digit);
+
+            // k >>= 1
+            for i in 0..4 {
+                k[i] = (k[i] >> 1) | (k[i + 1] << 63);
+            }
+            k[4] >>= 1;
+        }
+        digits
+    }
+
+    /// Multiplies the precomputed point by `scalar`.
+    ///
+    /// **This operation is variable time with respect to the scalar.**
+    pub fn mul(&self, scalar: &Fr) -> C {
+        let mut acc = C::identity();
+        for digit in self.wnaf(scalar).into_iter().rev() {
+            acc = acc.double();
+            let idx = (digit.unsigned_abs() / 2) as usize;
+            match digit.signum() {
+                1 => acc += self.table[idx],
+                -1 => acc -= self.table[idx],
+                _ => {}
+            }
+        }
+        acc
+    }
+}
+
+/// Reason a compressed point encoding was rejected by
+/// [`G1Affine::from_compressed_checked`] or
+/// [`G2Affine::from_compressed_checked`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointDecodeError {
+    /// The identity flag is set on an encoding other than the canonical
+    /// identity encoding.
+    InvalidIdentity,
+    /// The x-coordinate is not less than the modulus.
+    NonCanonicalX,
+    /// No point of the curve has this x-coordinate.
+    NotOnCurve,
+    /// The point is not in the prime order subgroup.
+    NotInSubgroup,
+}
+
+impl core::fmt::Display for PointDecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PointDecodeError::InvalidIdentity => write!(f, "invalid identity encoding"),
+            PointDecodeError::NonCanonicalX => write!(f, "x-coordinate is not canonical"),
+            PointDecodeError::NotOnCurve => write!(f, "x-coordinate has no point on the curve"),
+            PointDecodeError::NotInSubgroup => {
+                write!(f, "point is not in the prime order subgroup")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PointDecodeError {}
+
+impl G1Affine {
+    /// Negates every point of `points` in place. Only the y-coordinates
+    /// change, so no inversion is needed, and the identity `(0, 0)` is left
+    /// unchanged.
+    pub fn batch_negate(points: &mut [G1Affine]) {
+        for p in points.iter_mut() {
+            p.y = -p.y;
+        }
+    }
+
+    /// Adds `a` and `b` pairwise, returning affine points. All slopes share
+    /// a single field inversion through Montgomery's trick. Doublings and
+    /// sums involving or resulting in the identity are handled.
+    ///
+    /// **This operation is variable time.**
+    ///
+    /// This function will panic if `a` and `b` have a different length.
+    pub fn batch_add_affine(a: &[G1Affine], b: &[G1Affine]) -> Vec<G1Affine> {
+        assert_eq!(a.len(), b.len());
+
+        // slope = num / den, unless the result is trivially known
+        let mut known = vec![None; a.len()];
+        let mut nums = vec![Fq::zero(); a.len()];
+        let mut dens = vec![Fq::one(); a.len()];
+        for (i, (p, q)) in a.iter().zip(b.iter()).enumerate() {
+            if bool::from(p.is_identity()) {
+                known[i] = Some(*q);
+            } else if bool::from(q.is_identity()) {
+                known[i] = Some(*p);
+            } else if p.x != q.x {
+                nums[i] = q.y - p.y;
+                dens[i] = q.x - p.x;
+            } else if p.y == q.y && !bool::from(p.y.is_zero()) {
+                // doubling, with a = 0
+                nums[i] = p.x.square() * Fq::from(3);
+                dens[i] = p.y.double();
+            } else {
+                // q = -p
+                known[i] = Some(G1Affine::identity());
+            }
+        }
+
+        dens.iter_mut().batch_invert();
+
+        a.iter()
+            .zip(b.iter())
+            .zip(known)
+            .zip(nums.iter().zip(dens.iter()))
+            .map(|(((p, q), known), (num, den_inv))| {
+                known.unwrap_or_else(|| {
+                    let lambda = num * den_inv;
+                    let x = lambda.square() - p.x - q.x;
+                    let y = lambda * (p.x - x) - p.y;
+                    G1Affine { x, y }
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the 32-byte compressed encoding of this point: `x` in little
+    /// endian, with the top bit of the last byte flagging the identity and
+    /// the next bit holding the parity of `y`. This is the
+    /// [`GroupEncoding`] representation.
+    pub fn to_compressed(&self) -> [u8; 32] {
+        self.to_bytes().0
+    }
+
+    /// Decodes the output of [`G1Affine::to_compressed`], recovering `y`
+    /// from the curve equation.
+    ///
+    /// Fails if `x` is not below the modulus, if `x` has no matching `y`, or
+    /// if the identity flag is set on anything other than the canonical
+    /// identity encoding.
+    pub fn from_compressed(bytes: &[u8; 32]) -> CtOption<G1Affine> {
+        let is_identity_flag = Choice::from(bytes[31] >> 7);
+        let identity = G1Affine::identity().to_compressed();
+        let is_canonical_identity = bytes.ct_eq(&identity);
+
+        G1Affine::from_bytes(&G1Compressed(*bytes)).and_then(|p| {
+            let is_valid =
+                is_identity_flag & is_canonical_identity | !is_identity_flag & !p.is_identity();
+            CtOption::new(p, is_valid)
+        })
+    }
+
+    /// Decodes like [`G1Affine::from_compressed`], but reports why an
+    /// encoding is rejected. `G1` has cofactor one, so this never returns
+    /// [`PointDecodeError::NotInSubgroup`].
+    ///
+    /// **This operation is variable time**, as the error reveals which check
+    /// failed.
+    pub fn from_compressed_checked(bytes: &[u8; 32]) -> Result<G1Affine, PointDecodeError> {
+        if bytes[31] >> 7 == 1 {
+            return if *bytes == G1Affine::identity().to_compressed() {
+                Ok(G1Affine::identity())
+            } else {
+                Err(PointDecodeError::InvalidIdentity)
+            };
+        }
+
+        let mut x = *bytes;
+        x[31] &= 0b0011_1111;
+        let x = Option::<Fq>::from(Fq::from_bytes(&x)).ok_or(PointDecodeError::NonCanonicalX)?;
+        if !bool::from(G1::x_is_valid(&x)) {
+            return Err(PointDecodeError::NotOnCurve);
+        }
+        Ok(G1Affine::from_compressed(bytes).unwrap())
+    }
+
+    /// Same as [`G1Affine::from_compressed`]: `G1` has cofactor one, so
+    /// every point on the curve is in the prime order subgroup and there is
+    /// no subgroup check to skip.
+    pub fn from_compressed_unchecked(bytes: &[u8; 32]) -> CtOption<G1Affine> {
+        G1Affine::from_compressed(bytes)
+    }
+
+    /// Same as [`GroupEncoding::from_bytes`], which already only returns
+    /// points of the prime order subgroup since `G1` has cofactor one.
+    pub fn from_bytes_checked(bytes: &G1Compressed) -> CtOption<G1Affine> {
+        G1Affine::from_bytes(bytes)
+    }
+}
+
+impl G2Affine {
+    /// Returns the 64-byte compressed encoding of this point: `x` as
+    /// `c0 || c1` in little endian, with the top bit of the last byte
+    /// flagging the identity and the next bit holding the parity of `y.c0`.
+    /// This is the [`GroupEncoding`] representation.
+    pub fn to_compressed(&self) -> [u8; 64] {
+        self.to_bytes().0
+    }
+
+    /// Decodes the output of [`G2Affine::to_compressed`], recovering `y`
+    /// from the curve equation.
+    ///
+    /// Fails if either coordinate of `x` is not below the modulus, if `x`
+    /// has no matching `y`, if the point is not in the prime order subgroup,
+    /// or if the identity flag is set on anything other than the canonical
+    /// identity encoding.
+    pub fn from_compressed(bytes: &[u8; 64]) -> CtOption<G2Affine> {
+        G2Affine::from_compressed_unchecked(bytes).and_then(|p| {
+            let in_subgroup = p.is_in_correct_subgroup();
+            CtOption::new(p, in_subgroup)
+        })
+    }
+
+    /// Decodes like [`G2Affine::from_compressed`], but reports why an
+    /// encoding is rejected.
+    ///
+    /// **This operation is variable time**, as the error reveals which check
+    /// failed.
+    pub fn from_compressed_checked(bytes: &[u8; 64]) -> Result<G2Affine, PointDecodeError> {
+        if bytes[63] >> 7 == 1 {
+            return if *bytes == G2Affine::identity().to_compressed() {
+                Ok(G2Affine::identity())
+            } else {
+                Err(PointDecodeError::InvalidIdentity)
+            };
+        }
+
+        let mut x = *bytes;
+        x[63] &= 0b0011_1111;
+        let x = Option::<Fq2>::from(Fq2::from_bytes(&x)).ok_or(PointDecodeError::NonCanonicalX)?;
+        if !bool::from((x.square() * x + G2::b()).ct_quadratic_residue()) {
+            return Err(PointDecodeError::NotOnCurve);
+        }
+        let p = G2Affine::from_compressed_unchecked(bytes).unwrap();
+        if !bool::from(p.is_in_correct_subgroup()) {
+            return Err(PointDecodeError::NotInSubgroup);
+        }
+        Ok(p)
+    }
+
+    /// Like [`G2Affine::from_compressed`], but skips the prime order subgroup
+    /// check. Only use this on trusted input, as points outside the subgroup
+    /// enable small subgroup attacks.
+    pub fn from_compressed_unchecked(bytes: &[u8; 64]) -> CtOption<G2Affine> {
+        let is_identity_flag = Choice::from(bytes[63] >> 7);
+        let identity = G2Affine::identity().to_compressed();
+        let is_canonical_identity = bytes.ct_eq(&identity);
+
+        G2Affine::from_bytes(&G2Compressed(*bytes)).and_then(|p| {
+            let is_valid =
+                is_identity_flag & is_canonical_identity | !is_identity_flag & !p.is_identity();
+            CtOption::new(p, is_valid)
+        })
+    }
+
+    /// Like [`GroupEncoding::from_bytes`], but also fails if the point is not
+    /// in the prime order subgroup.
+    pub fn from_bytes_checked(bytes: &G2Compressed) -> CtOption<G2Affine> {
+        G2Affine::from_bytes(bytes).and_then(|p| {
+            let in_subgroup = p.is_in_correct_subgroup();
+            CtOption::new(p, in_subgroup)
+        })
+    }
+
+    /// Encodes this point in the layout of the EIP-197 pairing precompile:
+    /// `x.c1 || x.c0 || y.c1 || y.c0`, each coordinate as 32 big-endian bytes.
+    /// The identity is encoded as all zeros.
+    pub fn to_eip197_bytes(&self) -> [u8; 128] {
+        let mut res = [0u8; 128];
+        for (chunk, coord) in res
+            .chunks_mut(32)
+            .zip([self.x.c1, self.x.c0, self.y.c1, self.y.c0])
+        {
+            chunk.copy_from_slice(&coord.to_bytes());
+            chunk.reverse();
+        }
+        res
+    }
+
+    /// Decodes a point in the layout of [`G2Affine::to_eip197_bytes`],
+    /// failing if a coordinate is not canonical, or if the point is not on
+    /// the curve or not in the prime order subgroup.
+    pub fn from_eip197_bytes(bytes: &[u8; 128]) -> CtOption<G2Affine> {
+        let mut is_canonical = Choice::from(1u8);
+        let mut coords = [Fq::zero(); 4];
+        for (coord, chunk) in coords.iter_mut().zip(bytes.chunks(32)) {
+            let mut repr: [u8; 32] = chunk.try_into().unwrap();
+            repr.reverse();
+            let c = Fq::from_bytes(&repr);
+            is_canonical &= c.is_some();
+            *coord = c.unwrap_or(Fq::zero());
+        }
+        let [x_c1, x_c0, y_c1, y_c0] = coords;
+        let point = G2Affine {
+            x: Fq2::new(x_c0, x_c1),
+            y: Fq2::new(y_c0, y_c1),
+        };
+
+        let is_identity = point.x.is_zero() & point.y.is_zero();
+        let is_valid = point.is_on_curve() & point.is_in_correct_subgroup();
+        CtOption::new(
+            G2Affine::conditional_select(&point, &G2Affine::identity(), is_identity),
+            is_canonical & (is_identity | is_valid),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -204,6 +873,17 @@ mod test {
     crate::curve_testing_suite!(G1, G2);
     crate::curve_testing_suite!(G1, "hash_to_curve");
     crate::curve_testing_suite!(G1, "endo_consistency");
+    crate::curve_testing_suite!(G1, G2, "mul_reference");
+    crate::curve_testing_suite!(G1, G2, "mul_by_geometric");
+    crate::curve_testing_suite!(G1, G2, "identity_encodings");
+    crate::curve_testing_suite!(G1, G2, "double");
+    crate::curve_testing_suite!(G1, G2, "affine_neg");
+    crate::curve_testing_suite!(G1, G2, "scalar_mul_operators");
+    crate::curve_testing_suite!(G1, G2, "default_identity");
+    crate::curve_testing_suite!(G1, G2, "conditional_select");
+    crate::curve_testing_suite!(G1, G2, "sum");
+    crate::curve_testing_suite!(G1, G2, "from_xy");
+    crate::curve_testing_suite!(G1, G2, "from_affine");
     crate::curve_testing_suite!(
         G1,
         "endo",
@@ -267,6 +947,36 @@ mod test {
             ]
         )
     );
+    #[cfg(feature = "sha2")]
+    crate::curve_testing_suite!(
+        G1,
+        "hash_to_curve_vectors",
+        G1::encode_to_curve_sha256(b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_NU_"),
+        // List of (msg, (P.x, P.y)) taken from https://github.com/ConsenSys/gnark-crypto/blob/441dc0ffe639294b8d09e394f24ba7575577229c/ecc/bn254/hash_vectors_test.go#L4-L28
+        [
+            (
+                "",
+                (
+                    "1bb8810e2ceaf04786d4efd216fc2820ddd9363712efc736ada11049d8af5925",
+                    "1efbf8d54c60d865cce08437668ea30f5bf90d287dbd9b5af31da852915e8f11",
+                ),
+            ),
+            (
+                "abc",
+                (
+                    "0da4a96147df1f35b0f820bd35c6fac3b80e8e320de7c536b1e054667b22c332",
+                    "189bd3fbffe4c8740d6543754d95c790e44cd2d162858e3b733d2b8387983bb7",
+                ),
+            ),
+            (
+                "abcdef0123456789",
+                (
+                    "2ff727cfaaadb3acab713fa22d91f5fddab3ed77948f3ef6233d7ea9b03f4da1",
+                    "304080768fd2f87a852155b727f97db84b191e41970506f0326ed4046d1141aa",
+                ),
+            ),
+        ]
+    );
     crate::curve_testing_suite!(
         G1,
         "constants",
@@ -287,4 +997,648 @@ mod test {
         G2_GENERATOR_Y,
         Fr::MODULUS
     );
+
+    #[test]
+    fn test_mul_limbs() {
+        use rand_core::{OsRng, RngCore};
+
+        fn fr_from_u64_digits(limbs: &[u64]) -> Fr {
+            let shift = Fr::from(u64::MAX) + Fr::ONE;
+            limbs
+                .iter()
+                .rev()
+                .fold(Fr::ZERO, |acc, limb| acc * shift + Fr::from(*limb))
+        }
+
+        let p = G1::random(OsRng);
+        assert_eq!(p.mul_limbs(&[]), G1::identity());
+        assert_eq!(p.mul_limbs(&[0, 0]), G1::identity());
+        assert_eq!(p.mul_limbs(&[1]), p);
+        assert_eq!(G1::identity().mul_limbs(&[u64::MAX; 4]), G1::identity());
+
+        for n in 1..=6 {
+            for _ in 0..10 {
+                let limbs: Vec<u64> = (0..n).map(|_| OsRng.next_u64()).collect();
+                assert_eq!(p.mul_limbs(&limbs), p * fr_from_u64_digits(&limbs));
+            }
+        }
+    }
+
+    #[test]
+    fn test_eip197_encoding() {
+        use rand_core::OsRng;
+
+        // The G2 generator as passed to the EIP-197 pairing precompile
+        let generator = hex::decode(
+            "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+        )
+        .unwrap();
+        let generator: [u8; 128] = generator.try_into().unwrap();
+        assert_eq!(G2Affine::generator().to_eip197_bytes(), generator);
+        assert_eq!(
+            G2Affine::from_eip197_bytes(&generator).unwrap(),
+            G2Affine::generator()
+        );
+
+        assert_eq!(G2Affine::identity().to_eip197_bytes(), [0u8; 128]);
+        assert_eq!(
+            G2Affine::from_eip197_bytes(&[0u8; 128]).unwrap(),
+            G2Affine::identity()
+        );
+
+        for _ in 0..100 {
+            let point = G2::random(OsRng).to_affine();
+            let bytes = point.to_eip197_bytes();
+            assert_eq!(G2Affine::from_eip197_bytes(&bytes).unwrap(), point);
+        }
+
+        // components in the wrong order are not on the curve
+        let mut swapped = generator;
+        swapped[..32].copy_from_slice(&generator[32..64]);
+        swapped[32..64].copy_from_slice(&generator[..32]);
+        assert!(bool::from(G2Affine::from_eip197_bytes(&swapped).is_none()));
+
+        // non-canonical coordinate
+        let mut non_canonical = generator;
+        non_canonical[..32].copy_from_slice(&[0xff; 32]);
+        assert!(bool::from(
+            G2Affine::from_eip197_bytes(&non_canonical).is_none()
+        ));
+
+        // on the twist, but outside of the prime order subgroup
+        let point = core::iter::repeat_with(|| {
+            let x = Fq2::random(OsRng);
+            (x.square() * x + G2::b()).sqrt().map(|y| G2Affine { x, y })
+        })
+        .find_map(Option::<G2Affine>::from)
+        .unwrap();
+        assert!(bool::from(point.is_on_curve()));
+        assert!(bool::from(
+            G2Affine::from_eip197_bytes(&point.to_eip197_bytes()).is_none()
+        ));
+    }
+
+    #[test]
+    fn test_wnaf_base() {
+        use rand_core::OsRng;
+
+        for window in 2..=8 {
+            let base = G1::random(OsRng);
+            let wnaf = base.precompute_wnaf(window);
+
+            for scalar in [Fr::zero(), Fr::one(), -Fr::one(), Fr::from(1 << window)] {
+                assert_eq!(wnaf.mul(&scalar), base * scalar);
+            }
+
+            // reusing the same table
+            let scalars = (0..20).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+            let results = scalars.iter().map(|s| wnaf.mul(s)).collect::<Vec<_>>();
+            for (scalar, result) in scalars.iter().zip(results.iter()) {
+                assert_eq!(*result, base * scalar);
+                assert_eq!(wnaf.mul(scalar), *result);
+            }
+        }
+
+        let wnaf = G1::identity().precompute_wnaf(4);
+        assert_eq!(wnaf.mul(&Fr::random(OsRng)), G1::identity());
+    }
+
+    #[test]
+    fn test_batch_add_affine() {
+        use rand_core::OsRng;
+
+        let n = 100;
+        let mut a = (0..n)
+            .map(|_| G1::random(OsRng).to_affine())
+            .collect::<Vec<_>>();
+        let mut b = (0..n)
+            .map(|_| G1::random(OsRng).to_affine())
+            .collect::<Vec<_>>();
+
+        // doubling
+        b[0] = a[0];
+        // sum is the identity
+        b[1] = -a[1];
+        // identity operands
+        a[2] = G1Affine::identity();
+        b[3] = G1Affine::identity();
+        a[4] = G1Affine::identity();
+        b[4] = G1Affine::identity();
+
+        let sums = G1Affine::batch_add_affine(&a, &b);
+        assert_eq!(sums.len(), n);
+        for ((p, q), sum) in a.iter().zip(b.iter()).zip(sums.iter()) {
+            assert_eq!((p + q).to_affine(), *sum);
+        }
+        assert_eq!(sums[0], G1::from(a[0]).double().to_affine());
+        assert_eq!(sums[1], G1Affine::identity());
+
+        assert!(G1Affine::batch_add_affine(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_batch_negate() {
+        use rand_core::OsRng;
+
+        let mut points = (0..100)
+            .map(|_| G1::random(OsRng).to_affine())
+            .collect::<Vec<_>>();
+        points[7] = G1Affine::identity();
+        let original = points.clone();
+
+        G1Affine::batch_negate(&mut points);
+        for (p, neg) in original.iter().zip(points.iter()) {
+            assert_eq!(-p, *neg);
+            assert!(bool::from(neg.is_on_curve()));
+        }
+        assert_eq!(points[7], G1Affine::identity());
+
+        G1Affine::batch_negate(&mut points);
+        assert_eq!(points, original);
+
+        G1Affine::batch_negate(&mut []);
+    }
+
+    #[test]
+    fn test_group_encoding_repr_size() {
+        use core::mem::size_of;
+        use rand_core::OsRng;
+
+        const _: () = assert!(size_of::<<G1 as GroupEncoding>::Repr>() == 32);
+        const _: () = assert!(size_of::<<G1Affine as GroupEncoding>::Repr>() == 32);
+        const _: () = assert!(size_of::<<G2 as GroupEncoding>::Repr>() == 64);
+        const _: () = assert!(size_of::<<G2Affine as GroupEncoding>::Repr>() == 64);
+
+        fn check<G: GroupEncoding + Group>(len: usize) {
+            // buffers sized from `Repr` alone are enough to round trip
+            assert_eq!(G::Repr::default().as_ref().len(), len);
+            for p in [G::identity(), G::generator(), G::random(OsRng)] {
+                let bytes = p.to_bytes();
+                assert_eq!(bytes.as_ref().len(), len);
+
+                let mut repr = G::Repr::default();
+                repr.as_mut().copy_from_slice(bytes.as_ref());
+                assert_eq!(G::from_bytes(&repr).unwrap(), p);
+            }
+        }
+
+        check::<G1>(G1_COMPRESSED_SIZE);
+        check::<G2>(G2_COMPRESSED_SIZE);
+        assert_eq!(G1_COMPRESSED_SIZE, 32);
+        assert_eq!(G2_COMPRESSED_SIZE, 64);
+    }
+
+    #[test]
+    fn test_mul_glv() {
+        use rand_core::OsRng;
+
+        for _ in 0..1000 {
+            let p = G1::random(OsRng);
+            let k = Fr::random(OsRng);
+            assert_eq!(p.mul_glv(&k), p * k);
+        }
+
+        let p = G1::random(OsRng);
+        for k in [Fr::zero(), Fr::one(), -Fr::one(), Fr::ZETA, -Fr::ZETA] {
+            assert_eq!(p.mul_glv(&k), p * k);
+        }
+        assert_eq!(G1::identity().mul_glv(&Fr::random(OsRng)), G1::identity());
+    }
+
+    #[test]
+    fn test_compressed_encoding() {
+        use rand_core::OsRng;
+
+        let identity = G1Affine::identity().to_compressed();
+        assert_eq!(identity[31], 0b1000_0000);
+        assert_eq!(
+            G1Affine::from_compressed(&identity).unwrap(),
+            G1Affine::identity()
+        );
+        let identity = G2Affine::identity().to_compressed();
+        assert_eq!(identity[63], 0b1000_0000);
+        assert_eq!(
+            G2Affine::from_compressed(&identity).unwrap(),
+            G2Affine::identity()
+        );
+
+        for _ in 0..100 {
+            let p = G1::random(OsRng).to_affine();
+            let bytes = p.to_compressed();
+            assert_eq!(bytes, p.to_bytes().0);
+            assert_eq!(G1Affine::from_compressed(&bytes).unwrap(), p);
+            assert_eq!(
+                G1Affine::from_compressed(&(-p).to_compressed()).unwrap(),
+                -p
+            );
+
+            // identity flag on a nonzero x, or with the sign bit set
+            let mut bad = bytes;
+            bad[31] |= 0b1000_0000;
+            assert!(bool::from(G1Affine::from_compressed(&bad).is_none()));
+
+            let q = G2::random(OsRng).to_affine();
+            let bytes = q.to_compressed();
+            assert_eq!(bytes, q.to_bytes().0);
+            assert_eq!(G2Affine::from_compressed(&bytes).unwrap(), q);
+            assert_eq!(
+                G2Affine::from_compressed(&(-q).to_compressed()).unwrap(),
+                -q
+            );
+
+            let mut bad = bytes;
+            bad[63] |= 0b1000_0000;
+            assert!(bool::from(G2Affine::from_compressed(&bad).is_none()));
+        }
+
+        let mut bad = G1Affine::identity().to_compressed();
+        bad[31] |= 0b0100_0000;
+        assert!(bool::from(G1Affine::from_compressed(&bad).is_none()));
+        let mut bad = G2Affine::identity().to_compressed();
+        bad[63] |= 0b0100_0000;
+        assert!(bool::from(G2Affine::from_compressed(&bad).is_none()));
+
+        // x equal to the modulus, in each coordinate for G2
+        let mut modulus = (-Fq::one()).to_bytes();
+        modulus[0] += 1;
+        assert!(bool::from(G1Affine::from_compressed(&modulus).is_none()));
+        for offset in [0, 32] {
+            let mut bytes = [0u8; 64];
+            bytes[offset..offset + 32].copy_from_slice(&modulus);
+            assert!(bool::from(G2Affine::from_compressed(&bytes).is_none()));
+        }
+
+        // x with no matching y
+        let x = (1..)
+            .map(Fq::from)
+            .find(|x| bool::from((x.square() * x + G1::b()).sqrt().is_none()))
+            .unwrap();
+        assert!(bool::from(
+            G1Affine::from_compressed(&x.to_bytes()).is_none()
+        ));
+        let x = (1..)
+            .map(|c0| Fq2::new(Fq::from(c0), Fq::one()))
+            .find(|x| bool::from((x.square() * x + G2::b()).sqrt().is_none()))
+            .unwrap();
+        assert!(bool::from(
+            G2Affine::from_compressed(&x.to_bytes()).is_none()
+        ));
+
+        // on the twist but outside the prime order subgroup
+        let x = (1..)
+            .map(|c0| Fq2::new(Fq::from(c0), Fq::one()))
+            .find(|x| bool::from((x.square() * x + G2::b()).sqrt().is_some()))
+            .unwrap();
+        let bytes = x.to_bytes();
+        let p = G2Affine::from_bytes(&G2Compressed(bytes)).unwrap();
+        assert!(!bool::from(G2::from(p).is_torsion_free()));
+        assert!(bool::from(G2Affine::from_compressed(&bytes).is_none()));
+    }
+
+    #[test]
+    fn test_mul_blinded() {
+        use rand::rngs::mock::StepRng;
+        use rand_core::{OsRng, SeedableRng};
+        use rand_xorshift::XorShiftRng;
+
+        // counts the bytes drawn, to check that every call takes fresh randomness
+        struct CountingRng {
+            inner: XorShiftRng,
+            drawn: usize,
+        }
+
+        impl RngCore for CountingRng {
+            fn next_u32(&mut self) -> u32 {
+                self.drawn += 4;
+                self.inner.next_u32()
+            }
+            fn next_u64(&mut self) -> u64 {
+                self.drawn += 8;
+                self.inner.next_u64()
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                self.drawn += dest.len();
+                self.inner.fill_bytes(dest)
+            }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+
+        let mut rng = CountingRng {
+            inner: XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+                0xbc, 0xe5,
+            ]),
+            drawn: 0,
+        };
+        for _ in 0..100 {
+            let p = G1::random(OsRng);
+            let k = Fr::random(OsRng);
+            let drawn = rng.drawn;
+            assert_eq!(p.mul_blinded(&k, &mut rng), p * k);
+            assert!(rng.drawn > drawn);
+        }
+
+        // a constant RNG gives fixed shares, which must not change the result
+        let p = G1::random(OsRng);
+        let k = Fr::random(OsRng);
+        assert_eq!(p.mul_blinded(&k, StepRng::new(0, 0)), p * k);
+        assert_eq!(p.mul_blinded(&k, StepRng::new(u64::MAX, 0)), p * k);
+
+        assert_eq!(p.mul_blinded(&Fr::zero(), OsRng), G1::identity());
+        assert_eq!(
+            G1::identity().mul_blinded(&Fr::one(), OsRng),
+            G1::identity()
+        );
+    }
+
+    #[test]
+    fn test_hash_to_curve_steps() {
+        use crate::hash_to_curve::hash_to_field;
+
+        let domain_prefix = "QUUX-V01-CS02-with";
+        let dst = format!("{domain_prefix}-bn256_g1_XMD:BLAKE2b_SVDW_RO_");
+        let hasher = G1::hash_to_curve(domain_prefix);
+
+        for message in ["", "abc", "abcdef0123456789", &"a".repeat(512)] {
+            let [u0, u1]: [Fq; 2] = hash_to_field(dst.as_bytes(), message.as_bytes());
+            let q0 = G1::map_to_curve(u0);
+            let q1 = G1::map_to_curve(u1);
+            assert!(bool::from(q0.is_on_curve() & q1.is_on_curve()));
+
+            // G1 has cofactor one, so clearing it is the identity map
+            let p = (q0 + q1).clear_cofactor();
+            assert_eq!(p, q0 + q1);
+            assert_eq!(p, hasher(message.as_bytes()));
+        }
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn test_hash_to_curve_sha256_steps() {
+        use crate::hash_to_curve::hash_to_field_sha256;
+
+        let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+        let hasher = G1::hash_to_curve_sha256(dst);
+        let encoder = G1::encode_to_curve_sha256(dst);
+
+        for message in ["", "abc", "abcdef0123456789", &"a".repeat(512)] {
+            let [u0, u1]: [Fq; 2] = hash_to_field_sha256(dst, message.as_bytes());
+            let p = G1::map_to_curve(u0) + G1::map_to_curve(u1);
+            assert_eq!(p, hasher(message.as_bytes()));
+            assert_ne!(p, encoder(message.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_mul_by_cofactor_inv() {
+        use rand_core::OsRng;
+
+        for _ in 0..10 {
+            let p = G1::random(OsRng);
+            assert_eq!(p.mul_by_cofactor_inv(), p);
+
+            let q = G2::generator() * Fr::random(OsRng);
+            assert_eq!(q.mul_by_cofactor_inv().clear_cofactor(), q);
+            assert_eq!(q.clear_cofactor().mul_by_cofactor_inv(), q);
+        }
+        assert_eq!(G1::identity().mul_by_cofactor_inv(), G1::identity());
+        assert_eq!(G2::identity().mul_by_cofactor_inv(), G2::identity());
+    }
+
+    #[test]
+    fn test_g2_clear_cofactor() {
+        use rand_core::OsRng;
+
+        // psi acts as multiplication by p = 6x^2 mod r on the subgroup
+        let p = Fr::from(BN_X).square() * Fr::from(6);
+        for _ in 0..10 {
+            let q = G2::generator() * Fr::random(OsRng);
+            assert_eq!(q.psi(), q * p);
+            assert!(bool::from(q.to_affine().is_in_correct_subgroup()));
+        }
+        assert!(bool::from(G2Affine::identity().is_in_correct_subgroup()));
+        assert!(bool::from(G2Affine::generator().is_in_correct_subgroup()));
+
+        // points on the twist, mostly outside the subgroup
+        let mut outside = 0;
+        for c0 in 1..50 {
+            let x = Fq2::new(Fq::from(c0), Fq::one());
+            let y = match Option::from((x.square() * x + G2::b()).sqrt()) {
+                Some(y) => y,
+                None => continue,
+            };
+            let q = G2Affine { x, y };
+            assert!(bool::from(q.is_on_curve()));
+            assert_eq!(
+                bool::from(q.is_in_correct_subgroup()),
+                bool::from(G2::from(q).is_torsion_free())
+            );
+            outside += !bool::from(q.is_in_correct_subgroup()) as usize;
+
+            let cleared = G2::from(q).clear_cofactor();
+            assert!(!bool::from(cleared.is_identity()));
+            assert!(bool::from(cleared.is_torsion_free()));
+            assert!(bool::from(cleared.to_affine().is_in_correct_subgroup()));
+        }
+        assert!(outside > 0);
+    }
+
+    #[test]
+    fn test_from_bytes_checked() {
+        use rand_core::OsRng;
+
+        for _ in 0..10 {
+            let p = G1::random(OsRng).to_affine();
+            assert_eq!(G1Affine::from_bytes_checked(&p.to_bytes()).unwrap(), p);
+            let bytes = p.to_compressed();
+            assert_eq!(G1Affine::from_compressed_unchecked(&bytes).unwrap(), p);
+
+            let q = G2::random(OsRng).to_affine();
+            assert_eq!(G2Affine::from_bytes_checked(&q.to_bytes()).unwrap(), q);
+            let bytes = q.to_compressed();
+            assert_eq!(G2Affine::from_compressed_unchecked(&bytes).unwrap(), q);
+        }
+        let identity = G2Affine::identity();
+        assert!(bool::from(
+            G2Affine::from_bytes_checked(&identity.to_bytes()).is_some()
+        ));
+
+        // on the twist but outside the prime order subgroup
+        let x = (1..)
+            .map(|c0| Fq2::new(Fq::from(c0), Fq::one()))
+            .find(|x| bool::from((x.square() * x + G2::b()).sqrt().is_some()))
+            .unwrap();
+        let bytes = x.to_bytes();
+        let p = G2Affine::from_bytes(&G2Compressed(bytes)).unwrap();
+        assert!(!bool::from(p.is_in_correct_subgroup()));
+        assert!(bool::from(
+            G2Affine::from_bytes_checked(&G2Compressed(bytes)).is_none()
+        ));
+        assert!(bool::from(G2Affine::from_compressed(&bytes).is_none()));
+        assert_eq!(G2Affine::from_compressed_unchecked(&bytes).unwrap(), p);
+    }
+
+    #[test]
+    fn test_mul_gls() {
+        use rand_core::OsRng;
+
+        let p = Fr::from(BN_X).square() * Fr::from(6);
+        let edge = [Fr::zero(), Fr::one(), -Fr::one(), p, -p, Fr::from(u64::MAX)];
+        for k in edge.into_iter().chain((0..100).map(|_| Fr::random(OsRng))) {
+            let parts = G2::decompose_gls(&k);
+            let recomposed = parts.iter().rev().fold(Fr::zero(), |acc, part| {
+                let abs = Fr::from_u128(part.unsigned_abs());
+                acc * p + if *part < 0 { -abs } else { abs }
+            });
+            assert_eq!(recomposed, k);
+            assert!(parts
+                .iter()
+                .all(|part| part.unsigned_abs() >> GLS_BITS == 0));
+
+            let q = G2::random(OsRng);
+            assert_eq!(q.mul_gls(&k), q * k);
+        }
+        assert_eq!(G2::identity().mul_gls(&Fr::random(OsRng)), G2::identity());
+    }
+
+    #[test]
+    fn test_x_is_valid() {
+        use rand_core::OsRng;
+
+        let xs = [Fq::zero(), Fq::one(), -Fq::one(), G1Affine::generator().x];
+        let mut valid = 0;
+        for x in xs.into_iter().chain((0..1000).map(|_| Fq::random(OsRng))) {
+            let decoded = G1Affine::from_compressed(&x.to_bytes());
+            assert_eq!(
+                bool::from(G1::x_is_valid(&x)),
+                bool::from(decoded.is_some())
+            );
+            valid += bool::from(decoded.is_some()) as usize;
+        }
+        // about half of all x are valid
+        assert!((400..600).contains(&valid));
+    }
+
+    #[test]
+    fn test_from_compressed_checked() {
+        use rand_core::OsRng;
+
+        for _ in 0..100 {
+            let p = G1::random(OsRng).to_affine();
+            assert_eq!(G1Affine::from_compressed_checked(&p.to_compressed()), Ok(p));
+            let q = G2::random(OsRng).to_affine();
+            assert_eq!(G2Affine::from_compressed_checked(&q.to_compressed()), Ok(q));
+
+            // agrees with the constant time decoders on arbitrary bytes
+            let mut bytes = [0u8; 32];
+            OsRng.fill_bytes(&mut bytes);
+            bytes[31] &= 0b0111_1111;
+            assert_eq!(
+                G1Affine::from_compressed_checked(&bytes).ok(),
+                G1Affine::from_compressed(&bytes).into()
+            );
+            let mut bytes = [0u8; 64];
+            OsRng.fill_bytes(&mut bytes[..32]);
+            OsRng.fill_bytes(&mut bytes[32..]);
+            bytes[31] &= 0b0001_1111;
+            bytes[63] &= 0b0101_1111;
+            assert_eq!(
+                G2Affine::from_compressed_checked(&bytes).ok(),
+                G2Affine::from_compressed(&bytes).into()
+            );
+        }
+        assert_eq!(
+            G1Affine::from_compressed_checked(&G1Affine::identity().to_compressed()),
+            Ok(G1Affine::identity())
+        );
+        assert_eq!(
+            G2Affine::from_compressed_checked(&G2Affine::identity().to_compressed()),
+            Ok(G2Affine::identity())
+        );
+
+        // identity flag on a nonzero x
+        let mut bytes = G1Affine::generator().to_compressed();
+        bytes[31] |= 0b1000_0000;
+        assert_eq!(
+            G1Affine::from_compressed_checked(&bytes),
+            Err(PointDecodeError::InvalidIdentity)
+        );
+        let mut bytes = G2Affine::identity().to_compressed();
+        bytes[63] |= 0b0100_0000;
+        assert_eq!(
+            G2Affine::from_compressed_checked(&bytes),
+            Err(PointDecodeError::InvalidIdentity)
+        );
+
+        // x equal to the modulus
+        let mut modulus = (-Fq::one()).to_bytes();
+        modulus[0] += 1;
+        assert_eq!(
+            G1Affine::from_compressed_checked(&modulus),
+            Err(PointDecodeError::NonCanonicalX)
+        );
+        let mut bytes = [0u8; 64];
+        bytes[32..].copy_from_slice(&modulus);
+        assert_eq!(
+            G2Affine::from_compressed_checked(&bytes),
+            Err(PointDecodeError::NonCanonicalX)
+        );
+
+        // x with no matching y
+        let x = (1..)
+            .map(Fq::from)
+            .find(|x| !bool::from(G1::x_is_valid(x)))
+            .unwrap();
+        assert_eq!(
+            G1Affine::from_compressed_checked(&x.to_bytes()),
+            Err(PointDecodeError::NotOnCurve)
+        );
+        let x = (1..)
+            .map(|c0| Fq2::new(Fq::from(c0), Fq::one()))
+            .find(|x| bool::from((x.square() * x + G2::b()).sqrt().is_none()))
+            .unwrap();
+        assert_eq!(
+            G2Affine::from_compressed_checked(&x.to_bytes()),
+            Err(PointDecodeError::NotOnCurve)
+        );
+
+        // on the twist but outside the prime order subgroup
+        let x = (1..)
+            .map(|c0| Fq2::new(Fq::from(c0), Fq::one()))
+            .find(|x| bool::from((x.square() * x + G2::b()).sqrt().is_some()))
+            .unwrap();
+        assert_eq!(
+            G2Affine::from_compressed_checked(&x.to_bytes()),
+            Err(PointDecodeError::NotInSubgroup)
+        );
+    }
+
+    #[test]
+    fn test_mul_wnaf() {
+        use rand_core::OsRng;
+
+        let (p, q) = (G1::random(OsRng), G2::random(OsRng));
+        for window in 2..=8 {
+            let edge = [Fr::zero(), Fr::one(), -Fr::one(), Fr::from(1 << window)];
+            let scalars = edge.into_iter().chain((0..10).map(|_| Fr::random(OsRng)));
+            for scalar in scalars {
+                assert_eq!(p.mul_wnaf(&scalar, window), p * scalar);
+                assert_eq!(q.mul_wnaf(&scalar, window), q * scalar);
+            }
+        }
+
+        // zero scalar and identity point
+        assert_eq!(p.mul_wnaf(&Fr::zero(), 4), G1::identity());
+        assert_eq!(q.mul_wnaf(&Fr::zero(), 5), G2::identity());
+        assert_eq!(
+            G2::identity().mul_wnaf(&Fr::random(OsRng), 4),
+            G2::identity()
+        );
+    }
 }
@@ -3,20 +3,29 @@ use crate::bn256::curve::*;
 use crate::bn256::fq::*;
 use crate::bn256::fq12::*;
 use crate::bn256::fq2::*;
-use crate::bn256::fq6::FROBENIUS_COEFF_FQ6_C1;
+use crate::bn256::fq6::{Fq6, FROBENIUS_COEFF_FQ6_C1};
 use crate::bn256::fr::*;
 use crate::ff::{Field, PrimeField};
 use crate::group::cofactor::CofactorCurveAffine;
 use crate::group::Group;
+use crate::serde::SerdeObject;
+use alloc::{vec, vec::Vec};
 use core::borrow::Borrow;
 use core::iter::Sum;
 use core::ops::{Add, Mul, MulAssign, Neg, Sub};
 use pairing::{Engine, MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
 use rand_core::RngCore;
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 pub const BN_X: u64 = 4965661367192848881;
 
+// BN_X in NAF form
+pub const BN_X_NAF: [i8; 63] = [
+    1, 0, 0, 0, -1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, -1, 0, 1, 0, 1, 0, 1, 0, 0, 1,
+    0, 0, 0, 1, 0, -1, 0, -1, 0, -1, 0, 1, 0, 1, 0, 0, -1, 0, 1, 0, 1, 0, -1, 0, 0, 1, 0, 1, 0, 0,
+    0, 1,
+];
+
 // 6U+2 for in NAF form
 pub const SIX_U_PLUS_2_NAF: [i8; 65] = [
     0, 0, 0, 1, 0, 1, 0, -1, 0, 0, 1, -1, 0, 0, 1, 0, 0, 1, 1, 0, -1, 0, 0, 1, 0, -1, 0, 0, 0, 0,
@@ -57,11 +66,17 @@ impl PairingCurveAffine for G2Affine {
     }
 }
 
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Copy, Clone, Debug)]
 pub struct Gt(pub(crate) Fq12);
 
-impl std::fmt::Display for Gt {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Default for Gt {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl core::fmt::Display for Gt {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{self:?}")
     }
 }
@@ -92,9 +107,209 @@ impl Gt {
         Gt(Fq12::ONE)
     }
 
+    /// Returns true if this element is the identity.
+    pub fn is_identity(&self) -> Choice {
+        self.ct_eq(&Self::identity())
+    }
+
     /// Doubles this group element.
     pub fn double(&self) -> Gt {
-        Gt(self.0.square())
+        // `Gt` elements lie in the cyclotomic subgroup
+        let mut res = self.0;
+        res.cyclotomic_square();
+        Gt(res)
+    }
+
+    /// Computes $\prod_i b_i^{s_i}$ for `bases` $b_i$ and `scalars` $s_i$,
+    /// sharing one cyclotomic squaring per scalar bit across all bases.
+    ///
+    /// **This operation is variable time with respect to the scalars.**
+    ///
+    /// This function will panic if `bases` and `scalars` have a different
+    /// length.
+    pub fn multiexp(bases: &[Gt], scalars: &[Fr]) -> Gt {
+        assert_eq!(bases.len(), scalars.len());
+
+        let scalars: Vec<_> = scalars.iter().map(|s| s.to_repr()).collect();
+        let mut acc = Fq12::ONE;
+        for i in (0..Fr::NUM_BITS as usize).rev() {
+            acc.cyclotomic_square();
+            for (base, scalar) in bases.iter().zip(scalars.iter()) {
+                if (scalar[i / 8] >> (i % 8)) & 1 == 1 {
+                    acc.mul_assign(&base.0);
+                }
+            }
+        }
+        Gt(acc)
+    }
+
+    /// Compresses this element to the torus $T_2$ representation
+    /// $g = (1 + c_0) / c_1 \in F_{q^6}$ of $f = c_0 + c_1 w$.
+    /// The identity, the only element with $c_1 = 0$, is encoded as $g = 0$.
+    pub fn to_compressed(&self) -> [u8; GT_COMPRESSED_SIZE] {
+        let c1_inv = self.0.c1.invert().unwrap_or(Fq6::ZERO);
+        let g = (self.0.c0 + Fq6::ONE) * c1_inv;
+
+        let mut res = [0u8; GT_COMPRESSED_SIZE];
+        res[0..64].copy_from_slice(&g.c0.to_bytes());
+        res[64..128].copy_from_slice(&g.c1.to_bytes());
+        res[128..192].copy_from_slice(&g.c2.to_bytes());
+        res
+    }
+
+    /// Decompresses an element encoded by [`Gt::to_compressed`], failing if
+    /// the bytes are not canonical or the result is not in the order $r$ subgroup.
+    pub fn from_compressed(bytes: &[u8; GT_COMPRESSED_SIZE]) -> CtOption<Gt> {
+        // decode coordinate by coordinate, accumulating canonicity
+        let mut is_canonical = Choice::from(1u8);
+        let mut coeffs = [Fq::ZERO; 6];
+        for (coeff, chunk) in coeffs.iter_mut().zip(bytes.chunks(32)) {
+            let c = Fq::from_bytes(chunk.try_into().unwrap());
+            is_canonical &= c.is_some();
+            *coeff = c.unwrap_or(Fq::ZERO);
+        }
+        let g = Fq6 {
+            c0: Fq2::new(coeffs[0], coeffs[1]),
+            c1: Fq2::new(coeffs[2], coeffs[3]),
+            c2: Fq2::new(coeffs[4], coeffs[5]),
+        };
+
+        // f = (g + w) / (g - w), where g - w is never zero as v is not a square
+        let num = Fq12 {
+            c0: g,
+            c1: Fq6::ONE,
+        };
+        let den = Fq12 {
+            c0: g,
+            c1: -Fq6::ONE,
+        };
+        let f = num * den.invert().unwrap();
+        let f = Fq12::conditional_select(&f, &Fq12::ONE, g.is_zero());
+
+        CtOption::new(Gt(f), is_canonical & Self::is_in_subgroup(&f))
+    }
+
+    /// Returns true if `f` lies in the order $r$ subgroup of $F_{q^{12}}^*$,
+    /// that is if $f^r = 1$.
+    fn is_in_subgroup(f: &Fq12) -> Choice {
+        (f.pow_vartime(fr_to_limbs(&-Fr::ONE)) * f).ct_eq(&Fq12::ONE)
+    }
+
+    /// Raises this element to the power `exp`, which is the scalar
+    /// multiplication `self * exp` of the additively written group.
+    ///
+    /// Uses cyclotomic squarings, as `Gt` lies in the cyclotomic subgroup of
+    /// $F_{q^{12}}$, and is constant time with respect to `exp`.
+    pub fn pow(&self, exp: &Fr) -> Gt {
+        Gt(self.0.cyclotomic_exp(&fr_to_limbs(exp)))
+    }
+
+    /// Returns the twelve $F_q$ coefficients of this element, in the order
+    /// of its `Fq12`, `Fq6` and `Fq2` components.
+    fn coeffs(&self) -> [Fq; 12] {
+        let mut res = [Fq::ZERO; 12];
+        let fq2s = [self.0.c0, self.0.c1]
+            .into_iter()
+            .flat_map(|c| [c.c0, c.c1, c.c2]);
+        for (chunk, c) in res.chunks_mut(2).zip(fq2s) {
+            chunk.copy_from_slice(&[c.c0, c.c1]);
+        }
+        res
+    }
+
+    /// Inverts [`Gt::coeffs`], without checking subgroup membership.
+    fn from_coeffs(coeffs: [Fq; 12]) -> Gt {
+        let fq2 = |i: usize| Fq2::new(coeffs[2 * i], coeffs[2 * i + 1]);
+        let fq6 = |i: usize| Fq6 {
+            c0: fq2(3 * i),
+            c1: fq2(3 * i + 1),
+            c2: fq2(3 * i + 2),
+        };
+        Gt(Fq12 {
+            c0: fq6(0),
+            c1: fq6(1),
+        })
+    }
+}
+
+impl SerdeObject for Gt {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), 12 * Fq::size());
+        let mut chunks = bytes.chunks(Fq::size());
+        Self::from_coeffs([(); 12].map(|_| Fq::from_raw_bytes_unchecked(chunks.next().unwrap())))
+    }
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 12 * Fq::size() {
+            return None;
+        }
+        let coeffs: Option<Vec<Fq>> = bytes.chunks(Fq::size()).map(Fq::from_raw_bytes).collect();
+        let res = Self::from_coeffs(coeffs?.try_into().unwrap());
+        // Check that the element is in the target group.
+        bool::from(Self::is_in_subgroup(&res.0)).then_some(res)
+    }
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(12 * Fq::size());
+        for coeff in self.coeffs() {
+            res.extend_from_slice(&coeff.to_raw_bytes());
+        }
+        res
+    }
+
+    #[cfg(feature = "std")]
+    fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+        Self::from_coeffs([(); 12].map(|_| Fq::read_raw_unchecked(reader)))
+    }
+    #[cfg(feature = "std")]
+    fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut coeffs = [Fq::ZERO; 12];
+        for coeff in coeffs.iter_mut() {
+            *coeff = Fq::read_raw(reader)?;
+        }
+        let res = Self::from_coeffs(coeffs);
+        if bool::from(Self::is_in_subgroup(&res.0)) {
+            Ok(res)
+        } else {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Gt element is not in the target group",
+            ))
+        }
+    }
+    #[cfg(feature = "std")]
+    fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for coeff in self.coeffs() {
+            coeff.write_raw(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Size of the torus compressed encoding of [`Gt`], a single $F_{q^6}$ element.
+pub const GT_COMPRESSED_SIZE: usize = 192;
+
+#[cfg(feature = "derive_serde")]
+impl serde::Serialize for Gt {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = self.to_compressed();
+        if serializer.is_human_readable() {
+            hex::serde::serialize(bytes, serializer)
+        } else {
+            serde_arrays::serialize(&bytes, serializer)
+        }
+    }
+}
+
+#[cfg(feature = "derive_serde")]
+impl<'de> serde::Deserialize<'de> for Gt {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error as _;
+        let bytes = if deserializer.is_human_readable() {
+            hex::serde::deserialize(deserializer)?
+        } else {
+            serde_arrays::deserialize::<_, u8, GT_COMPRESSED_SIZE>(deserializer)?
+        };
+        Option::from(Self::from_compressed(&bytes))
+            .ok_or_else(|| D::Error::custom("deserialized bytes don't encode a valid Gt element"))
     }
 }
 
@@ -137,24 +352,18 @@ impl<'a, 'b> Sub<&'b Gt> for &'a Gt {
     }
 }
 
+/// Returns the little-endian `u64` limbs of the canonical representation of
+/// `s`.
+fn fr_to_limbs(s: &Fr) -> [u64; 4] {
+    let repr = s.to_repr();
+    core::array::from_fn(|i| u64::from_le_bytes(repr[8 * i..8 * (i + 1)].try_into().unwrap()))
+}
+
 impl<'a, 'b> Mul<&'b Fr> for &'a Gt {
     type Output = Gt;
 
     fn mul(self, other: &'b Fr) -> Self::Output {
-        let mut acc = Gt::identity();
-
-        for bit in other
-            .to_repr()
-            .iter()
-            .rev()
-            .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
-            .skip(1)
-        {
-            acc = acc.double();
-            acc = Gt::conditional_select(&acc, &(acc + self), bit);
-        }
-
-        acc
+        self.pow(other)
     }
 }
 
@@ -193,7 +402,7 @@ impl Group for Gt {
     }
 
     fn is_identity(&self) -> Choice {
-        self.ct_eq(&Self::identity())
+        self.is_identity()
     }
 
     #[must_use]
@@ -458,18 +667,6 @@ impl MillerLoopResult for Gt {
     type Gt = Self;
     // pub fn final_exponentiation(r: &Fq12) -> CtOption<Fq12> {
     fn final_exponentiation(&self) -> Gt {
-        fn exp_by_x(f: &mut Fq12) {
-            let x = BN_X;
-            let mut res = Fq12::ONE;
-            for i in (0..64).rev() {
-                res.cyclotomic_square();
-                if ((x >> i) & 1) == 1 {
-                    res.mul_assign(f);
-                }
-            }
-            *f = res;
-        }
-
         let r = self.0;
         let mut f1 = self.0;
         f1.conjugate();
@@ -490,14 +687,11 @@ impl MillerLoopResult for Gt {
                 let mut fp3 = fp2;
                 fp3.frobenius_map(1);
 
-                let mut fu = r;
-                exp_by_x(&mut fu);
+                let fu = r.exp_by_x();
 
-                let mut fu2 = fu;
-                exp_by_x(&mut fu2);
+                let fu2 = fu.exp_by_x();
 
-                let mut fu3 = fu2;
-                exp_by_x(&mut fu3);
+                let fu3 = fu2.exp_by_x();
 
                 let mut y3 = fu;
                 y3.frobenius_map(1);
@@ -836,3 +1030,317 @@ fn random_miller_loop_tests() {
         assert_eq!(abcd, abcd_with_double_loop);
     }
 }
+
+#[test]
+fn test_multi_miller_loop_prepared_reuse() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // a fixed G2 point, prepared once and reused across pairings
+    let q = G2Affine::from(G2::random(&mut rng));
+    let q_prepared = G2Prepared::from(q);
+
+    let ps = (0..10)
+        .map(|_| G1Affine::from(G1::random(&mut rng)))
+        .collect::<Vec<_>>();
+    for p in ps.iter() {
+        assert_eq!(
+            multi_miller_loop(&[(p, &q_prepared)]).final_exponentiation(),
+            pairing(p, &q)
+        );
+    }
+
+    let terms = ps.iter().map(|p| (p, &q_prepared)).collect::<Vec<_>>();
+    let expected = ps
+        .iter()
+        .fold(Gt::identity(), |acc, p| acc + pairing(p, &q));
+    assert_eq!(multi_miller_loop(&terms).final_exponentiation(), expected);
+}
+
+#[test]
+fn test_gt_multiexp() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    assert_eq!(Gt::multiexp(&[], &[]), Gt::identity());
+
+    for n in [1, 2, 5] {
+        let bases = (0..n)
+            .map(|_| {
+                pairing(
+                    &G1Affine::from(G1::random(&mut rng)),
+                    &G2Affine::from(G2::random(&mut rng)),
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut scalars = (0..n).map(|_| Fr::random(&mut rng)).collect::<Vec<_>>();
+        scalars[0] = -Fr::one();
+
+        let expected = bases
+            .iter()
+            .zip(scalars.iter())
+            .fold(Gt::identity(), |acc, (base, scalar)| acc + base * scalar);
+        assert_eq!(Gt::multiexp(&bases, &scalars), expected);
+
+        let zeros = vec![Fr::zero(); n];
+        assert_eq!(Gt::multiexp(&bases, &zeros), Gt::identity());
+    }
+}
+
+#[test]
+fn test_gt_identity() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    assert!(bool::from(Gt::identity().is_identity()));
+    assert_eq!(Gt::default(), Gt::identity());
+    assert!(bool::from(Gt::default().is_identity()));
+
+    for _ in 0..100 {
+        let p = G1Affine::from(G1::random(&mut rng));
+        let q = G2Affine::from(G2::random(&mut rng));
+
+        assert!(bool::from(pairing(&G1Affine::identity(), &q).is_identity()));
+        assert!(bool::from(pairing(&p, &G2Affine::identity()).is_identity()));
+
+        let g = pairing(&p, &q);
+        assert!(!bool::from(g.is_identity()));
+        assert_eq!(Gt::identity() + g, g);
+        assert_eq!(g + Gt::identity(), g);
+        assert!(bool::from((g - g).is_identity()));
+    }
+}
+
+#[test]
+fn test_gt_ct_eq() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..20 {
+        let a = pairing(
+            &G1Affine::from(G1::random(&mut rng)),
+            &G2Affine::from(G2::random(&mut rng)),
+        );
+        let b = a.double();
+        assert!(bool::from(a.ct_eq(&a.clone())));
+        assert!(!bool::from(a.ct_eq(&b)));
+        assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+
+        // every Fq coefficient takes part in the comparison
+        for i in 0..12 {
+            let mut coeffs = a.coeffs();
+            coeffs[i] += Fq::ONE;
+            let c = Gt::from_coeffs(coeffs);
+            assert!(!bool::from(a.ct_eq(&c)));
+            assert!(!bool::from(a.0.ct_eq(&c.0)));
+            assert_eq!(a.0 == c.0, bool::from(a.0.ct_eq(&c.0)));
+        }
+
+        // Fq12 agrees with the derived PartialEq
+        let (x, y) = (Fq12::random(&mut rng), Fq12::random(&mut rng));
+        assert_eq!(bool::from(x.ct_eq(&y)), x == y);
+        assert!(bool::from(x.ct_eq(&x.clone())));
+    }
+}
+
+#[test]
+fn test_gt_compression() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let identity = Gt::identity().to_compressed();
+    assert_eq!(identity, [0u8; GT_COMPRESSED_SIZE]);
+    assert_eq!(Gt::from_compressed(&identity).unwrap(), Gt::identity());
+
+    for _ in 0..20 {
+        let g = pairing(
+            &G1Affine::from(G1::random(&mut rng)),
+            &G2Affine::from(G2::random(&mut rng)),
+        );
+        let bytes = g.to_compressed();
+        assert_eq!(Gt::from_compressed(&bytes).unwrap(), g);
+        assert_eq!(Gt::from_compressed(&(-g).to_compressed()).unwrap(), -g);
+
+        // a random torus element is almost never in the order r subgroup
+        let mut bytes = [0u8; GT_COMPRESSED_SIZE];
+        bytes[..32].copy_from_slice(&Fq::random(&mut rng).to_bytes());
+        assert!(bool::from(Gt::from_compressed(&bytes).is_none()));
+    }
+
+    // non-canonical coordinate
+    assert!(bool::from(
+        Gt::from_compressed(&[0xff; GT_COMPRESSED_SIZE]).is_none()
+    ));
+}
+
+#[cfg(feature = "derive_serde")]
+#[test]
+fn test_gt_serde() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let mut elements = vec![Gt::identity()];
+    for _ in 0..10 {
+        elements.push(pairing(
+            &G1Affine::from(G1::random(&mut rng)),
+            &G2Affine::from(G2::random(&mut rng)),
+        ));
+    }
+
+    for g in elements {
+        let bytes = bincode::serialize(&g).unwrap();
+        assert_eq!(bytes.len(), GT_COMPRESSED_SIZE);
+        let g_rec: Gt = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(g, g_rec);
+
+        let json = serde_json::to_string(&g).unwrap();
+        assert_eq!(json, format!("\"{}\"", hex::encode(g.to_compressed())));
+        let g_rec: Gt = serde_json::from_str(&json).unwrap();
+        assert_eq!(g, g_rec);
+    }
+
+    let identity_json = serde_json::to_string(&Gt::identity()).unwrap();
+    assert_eq!(
+        identity_json,
+        format!("\"{}\"", "00".repeat(GT_COMPRESSED_SIZE))
+    );
+
+    let invalid = format!("\"{}\"", "ff".repeat(GT_COMPRESSED_SIZE));
+    assert!(serde_json::from_str::<Gt>(&invalid).is_err());
+}
+
+#[test]
+fn test_gt_pow() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..10 {
+        let p = G1Affine::from(G1::random(&mut rng));
+        let q = G2Affine::from(G2::random(&mut rng));
+        let a = Fr::random(&mut rng);
+
+        let e = pairing(&p, &q);
+        assert_eq!(pairing(&G1Affine::from(p * a), &q), e.pow(&a));
+        assert_eq!(pairing(&p, &G2Affine::from(q * a)), e.pow(&a));
+        assert_eq!(e.pow(&a), e * a);
+
+        // agrees with the generic Fq12 exponentiation
+        assert_eq!(e.pow(&a).0, e.0.pow_vartime(fr_to_limbs(&a)));
+
+        assert_eq!(e.pow(&Fr::ZERO), Gt::identity());
+        assert_eq!(e.pow(&Fr::ONE), e);
+        assert_eq!(e.pow(&-Fr::ONE), -e);
+    }
+}
+
+#[test]
+fn test_gt_serde_object() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let mut elements = vec![Gt::identity()];
+    for _ in 0..10 {
+        elements.push(pairing(
+            &G1Affine::from(G1::random(&mut rng)),
+            &G2Affine::from(G2::random(&mut rng)),
+        ));
+    }
+
+    for g in elements {
+        let bytes = g.to_raw_bytes();
+        assert_eq!(bytes.len(), 12 * Fq::size());
+        assert_eq!(Gt::from_raw_bytes(&bytes).unwrap(), g);
+        assert_eq!(Gt::from_raw_bytes_unchecked(&bytes), g);
+
+        #[cfg(feature = "std")]
+        {
+            let mut buf = Vec::new();
+            g.write_raw(&mut buf).unwrap();
+            assert_eq!(buf, bytes);
+            assert_eq!(Gt::read_raw(&mut &buf[..]).unwrap(), g);
+            assert_eq!(Gt::read_raw_unchecked(&mut &buf[..]), g);
+        }
+
+        assert!(Gt::from_raw_bytes(&bytes[1..]).is_none());
+    }
+
+    // a random Fq12 element is not in the target group
+    for _ in 0..10 {
+        let f = Gt(Fq12::random(&mut rng));
+        let bytes = f.to_raw_bytes();
+        assert!(Gt::from_raw_bytes(&bytes).is_none());
+        #[cfg(feature = "std")]
+        assert!(Gt::read_raw(&mut &bytes[..]).is_err());
+        assert_eq!(Gt::from_raw_bytes_unchecked(&bytes), f);
+    }
+
+    // non-canonical coefficient
+    assert!(Gt::from_raw_bytes(&[0xff; 12 * 32]).is_none());
+}
+
+#[test]
+fn test_eip197_pairing_precompile() {
+    // Runs the `ecPairing` precompile of EIP-197: 192 byte chunks of a G1
+    // point `x || y` and a G2 point in the layout of `to_eip197_bytes`,
+    // returning whether the product of their pairings is one.
+    fn ec_pairing(input: &[u8]) -> bool {
+        assert_eq!(input.len() % 192, 0);
+        let terms: Vec<(G1Affine, G2Prepared)> = input
+            .chunks(192)
+            .map(|chunk| {
+                let [x, y] = [&chunk[..32], &chunk[32..64]].map(|bytes| {
+                    let mut repr: [u8; 32] = bytes.try_into().unwrap();
+                    repr.reverse();
+                    Fq::from_bytes(&repr).unwrap()
+                });
+                let g1 = if bool::from(x.is_zero() & y.is_zero()) {
+                    G1Affine::identity()
+                } else {
+                    G1Affine::from_xy(x, y).unwrap()
+                };
+                let g2 = G2Affine::from_eip197_bytes(&chunk[64..].try_into().unwrap()).unwrap();
+                (g1, G2Prepared::from_affine(g2))
+            })
+            .collect();
+        let terms: Vec<_> = terms.iter().map(|(g1, g2)| (g1, g2)).collect();
+        bool::from(
+            multi_miller_loop(&terms)
+                .final_exponentiation()
+                .is_identity(),
+        )
+    }
+
+    // Reference: the `empty_data`, `one_point` and `two_point_match_2` cases
+    // of go-ethereum's core/vm/testdata/precompiles/bn256Pairing.json, which
+    // expect the outputs 1, 0 and 1.
+    let g1 = "0000000000000000000000000000000000000000000000000000000000000001\
+              0000000000000000000000000000000000000000000000000000000000000002";
+    let neg_g1 = "0000000000000000000000000000000000000000000000000000000000000001\
+                  30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45";
+    let g2 = "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+              1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+              090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+              12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa";
+
+    assert!(ec_pairing(&[]));
+    assert!(!ec_pairing(&hex::decode(format!("{g1}{g2}")).unwrap()));
+    assert!(ec_pairing(
+        &hex::decode(format!("{g1}{g2}{neg_g1}{g2}")).unwrap()
+    ));
+}
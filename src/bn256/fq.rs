@@ -10,6 +10,7 @@ use crate::{
     field_bits, field_common, impl_add_binop_specify_output, impl_binops_additive,
     impl_binops_additive_specify_output, impl_binops_multiplicative,
     impl_binops_multiplicative_mixed, impl_from_u64, impl_sub_binop_specify_output, impl_sum_prod,
+    impl_u64_assign_ops,
 };
 use core::convert::TryInto;
 use core::fmt;
@@ -143,6 +144,7 @@ field_common!(
     R3
 );
 impl_sum_prod!(Fq);
+impl_u64_assign_ops!(Fq);
 impl_from_u64!(Fq, R2);
 
 #[cfg(not(feature = "asm"))]
@@ -286,13 +288,34 @@ impl WithSmallOrderMulGroup<3> for Fq {
 mod test {
     use super::*;
     crate::field_testing_suite!(Fq, "field_arithmetic");
+    crate::field_testing_suite!(Fq, "mul_add");
     crate::field_testing_suite!(Fq, "conversion");
     crate::field_testing_suite!(Fq, "serialization");
     crate::field_testing_suite!(Fq, "quadratic_residue");
+    crate::field_testing_suite!(Fq, "is_square");
     crate::field_testing_suite!(Fq, "bits");
+    crate::field_testing_suite!(Fq, "bits_cross_width");
+    crate::field_testing_suite!(Fq, "ct_less_than_power_of_two");
+    crate::field_testing_suite!(Fq, "from_repr_slice");
+    crate::field_testing_suite!(Fq, "serialized_size");
+    crate::field_testing_suite!(Fq, "mul_by_u64");
+    crate::field_testing_suite!(Fq, "conditional_select_slice");
+    crate::field_testing_suite!(Fq, "batch_invert");
+    crate::field_testing_suite!(Fq, "pow_fixed_window");
+    crate::field_testing_suite!(Fq, "try_from_bytes");
+    crate::field_testing_suite!(Fq, "sqrt_alt");
+    crate::field_testing_suite!(Fq, "sub_borrow");
+    crate::field_testing_suite!(Fq, "halve");
+    crate::field_testing_suite!(Fq, "u64_assign_ops");
+    crate::field_testing_suite!(Fq, "raw_vs_from");
+    crate::field_testing_suite!(Fq, "bytes_be");
+    crate::field_testing_suite!(Fq, "pow_ct");
+    crate::field_testing_suite!(Fq, "le_bits_array");
+    crate::field_testing_suite!(Fq, "from_raw_checked");
     crate::field_testing_suite!(Fq, "serialization_check");
     crate::field_testing_suite!(Fq, "constants", MODULUS_STR);
     crate::field_testing_suite!(Fq, "sqrt");
+    crate::field_testing_suite!(Fq, "sqrt_canonical");
     crate::field_testing_suite!(Fq, "zeta");
     crate::field_testing_suite!(
         Fq,
@@ -360,4 +383,11 @@ mod test {
             ]),
         ]
     );
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_from_raw_non_canonical() {
+        Fq::from_raw(MODULUS.0);
+    }
 }
@@ -1,7 +1,9 @@
 use super::fq::Fq;
 use super::fq2::Fq2;
 use super::fq6::Fq6;
+use super::BN_X_NAF;
 use crate::ff::Field;
+use alloc::vec::Vec;
 use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
@@ -251,6 +253,33 @@ impl Fq12 {
         })
     }
 
+    /// Inverts every element of `elems` in place with Montgomery's trick,
+    /// using a single `Fq12` inversion. Zero elements are left unchanged and
+    /// the returned `CtOption` is none if any element was zero.
+    pub fn batch_invert(elems: &mut [Fq12]) -> CtOption<()> {
+        let mut all_nonzero = Choice::from(1u8);
+        let mut acc = Fq12::one();
+        let mut prefix = Vec::with_capacity(elems.len());
+        for elem in elems.iter() {
+            prefix.push(acc);
+            let is_zero = elem.is_zero();
+            all_nonzero &= !is_zero;
+            acc = Fq12::conditional_select(&(acc * elem), &acc, is_zero);
+        }
+
+        // acc is a product of nonzero elements, so it is invertible
+        acc = acc.invert().unwrap();
+
+        for (elem, prefix) in elems.iter_mut().zip(prefix).rev() {
+            let is_zero = elem.is_zero();
+            let inv = acc * prefix;
+            acc = Fq12::conditional_select(&(acc * *elem), &acc, is_zero);
+            *elem = Fq12::conditional_select(&inv, elem, is_zero);
+        }
+
+        CtOption::new((), all_nonzero)
+    }
+
     pub fn cyclotomic_square(&mut self) {
         fn fp4_square(c0: &mut Fq2, c1: &mut Fq2, a0: &Fq2, a1: &Fq2) {
             let t0 = a0.square();
@@ -296,6 +325,58 @@ impl Fq12 {
         t2.double_assign();
         self.c0.c2 = t2 + t5;
     }
+
+    /// Raises `self` to `exp`, given as little-endian `u64` limbs, with
+    /// square-and-multiply using [`Fq12::cyclotomic_square`].
+    ///
+    /// `self` must lie in the cyclotomic subgroup, e.g. be the output of the
+    /// final exponentiation. Runs in constant time with respect to `exp` for a
+    /// fixed number of limbs.
+    pub fn cyclotomic_exp(&self, exp: &[u64]) -> Fq12 {
+        let mut res = Fq12::ONE;
+        for limb in exp.iter().rev() {
+            for i in (0..64).rev() {
+                res.cyclotomic_square();
+                let bit = Choice::from(((limb >> i) & 1) as u8);
+                res = Fq12::conditional_select(&res, &(res * self), bit);
+            }
+        }
+        res
+    }
+
+    /// Raises `self` to the BN parameter $x$, walking its NAF.
+    ///
+    /// `self` must lie in the cyclotomic subgroup, where inversion is
+    /// conjugation; this holds after the easy part of the final
+    /// exponentiation. Runs in variable time with respect to the public $x$
+    /// only.
+    pub fn exp_by_x(&self) -> Fq12 {
+        let mut inv = *self;
+        inv.conjugate();
+
+        let mut res = Fq12::ONE;
+        for digit in BN_X_NAF.iter().rev() {
+            res.cyclotomic_square();
+            match digit {
+                1 => res.mul_assign(self),
+                -1 => res.mul_assign(&inv),
+                _ => {}
+            }
+        }
+        res
+    }
+
+    /// Raises `self` to $-x$. See [`Fq12::exp_by_x`].
+    pub fn exp_by_neg_x(&self) -> Fq12 {
+        let mut res = self.exp_by_x();
+        res.conjugate();
+        res
+    }
+
+    /// Variable time `self^exp` through a one-off [`PowCache`](crate::ff_ext::pow::PowCache).
+    pub fn pow_fixed_window(&self, exp: &[u64], window_bits: usize) -> Self {
+        crate::ff_ext::pow::PowCache::new(self, window_bits).pow(exp)
+    }
 }
 
 impl Field for Fq12 {
@@ -493,6 +574,7 @@ pub const FROBENIUS_COEFF_FQ12_C1: [Fq2; 12] = [
 mod test {
     use super::*;
     crate::field_testing_suite!(Fq12, "field_arithmetic");
+    crate::field_testing_suite!(Fq12, "pow_fixed_window");
     // extension field-specific
     crate::field_testing_suite!(Fq12, "f12_tests", Fq6, Fq2);
     crate::field_testing_suite!(
@@ -509,4 +591,102 @@ mod test {
             0x30644e72e131a029,
         ]
     );
+    crate::field_testing_suite!(
+        Fq12,
+        "frobenius_coeffs",
+        // p: modulus of base field (Here, Fq::MODULUS)
+        [
+            0x3c208c16d87cfd47,
+            0x97816a916871ca8d,
+            0xb85045b68181585d,
+            0x30644e72e131a029,
+        ],
+        // non_residue = u + 9
+        Fq2::new(Fq::from(9), Fq::one()),
+        [FROBENIUS_COEFF_FQ12_C1, 1, 6]
+    );
+
+    #[test]
+    fn test_batch_invert() {
+        use rand_core::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for n in [0, 1, 2, 10, 33] {
+            let elems: Vec<Fq12> = (0..n).map(|_| Fq12::random(&mut rng)).collect();
+            let mut inverted = elems.clone();
+            assert!(bool::from(Fq12::batch_invert(&mut inverted).is_some()));
+            for (elem, inv) in elems.iter().zip(inverted.iter()) {
+                assert_eq!(elem.invert().unwrap(), *inv);
+            }
+
+            if n > 0 {
+                let mut elems = elems;
+                elems[n / 2] = Fq12::zero();
+                let mut inverted = elems.clone();
+                assert!(bool::from(Fq12::batch_invert(&mut inverted).is_none()));
+                for (elem, inv) in elems.iter().zip(inverted.iter()) {
+                    assert_eq!(elem.invert().unwrap_or(Fq12::zero()), *inv);
+                }
+            }
+        }
+    }
+
+    /// Returns a random element of the cyclotomic subgroup, as
+    /// `f^((p^6 - 1)(p^2 + 1))` for a random `f`. The `p^6 - 1` power alone is
+    /// only unitary; the cyclotomic squaring also needs the `p^2 + 1` power.
+    fn random_cyclotomic(rng: &mut impl RngCore) -> Fq12 {
+        let f = Fq12::random(&mut *rng);
+        let mut g = f;
+        g.conjugate();
+        g.mul_assign(&f.invert().unwrap());
+        let mut h = g;
+        h.frobenius_map(2);
+        g * h
+    }
+
+    #[test]
+    fn test_exp_by_x() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        // the NAF encodes x
+        let x = BN_X_NAF
+            .iter()
+            .rev()
+            .fold(0i128, |acc, digit| 2 * acc + *digit as i128);
+        assert_eq!(x, crate::bn256::BN_X as i128);
+
+        for _ in 0..10 {
+            let g = random_cyclotomic(&mut rng);
+            let expected = g.pow_vartime([crate::bn256::BN_X]);
+            assert_eq!(g.exp_by_x(), expected);
+            assert_eq!(g.exp_by_neg_x(), expected.invert().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_cyclotomic_square() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..10 {
+            let g = random_cyclotomic(&mut rng);
+            let mut sq = g;
+            sq.cyclotomic_square();
+            assert_eq!(sq, g.square());
+
+            let exp = [rng.next_u64(), rng.next_u64(), rng.next_u64()];
+            assert_eq!(g.cyclotomic_exp(&exp), g.pow_vartime(exp));
+            assert_eq!(g.cyclotomic_exp(&[]), Fq12::ONE);
+        }
+    }
 }
@@ -1,10 +1,11 @@
-use super::fq::{Fq, NEGATIVE_ONE};
+use super::fq::Fq;
 use crate::ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
 use crate::ff_ext::Legendre;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::convert::TryInto;
 use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
-use std::cmp::Ordering;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "derive_serde")]
@@ -156,8 +157,8 @@ impl Fq2 {
         let c1 = Fq::from_bytes(bytes[32..64].try_into().unwrap());
         CtOption::new(
             Fq2 {
-                c0: c0.unwrap(),
-                c1: c1.unwrap(),
+                c0: c0.unwrap_or(Fq::zero()),
+                c1: c1.unwrap_or(Fq::zero()),
             },
             c0.is_some() & c1.is_some(),
         )
@@ -229,6 +230,14 @@ impl Fq2 {
         t
     }
 
+    /// Multiplies both components by the base field element `s`.
+    pub fn mul_by_base(&self, s: &Fq) -> Fq2 {
+        Self {
+            c0: self.c0 * s,
+            c1: self.c1 * s,
+        }
+    }
+
     pub fn square(&self) -> Self {
         let mut t = *self;
         t.square_assign();
@@ -303,12 +312,22 @@ impl Fq2 {
         t1 = t1.square();
         t1 + t0
     }
+
+    /// Variable time `self^exp` through a one-off [`PowCache`](crate::ff_ext::pow::PowCache).
+    pub fn pow_fixed_window(&self, exp: &[u64], window_bits: usize) -> Self {
+        crate::ff_ext::pow::PowCache::new(self, window_bits).pow(exp)
+    }
 }
 
 impl Legendre for Fq2 {
     fn legendre(&self) -> i64 {
         self.norm().legendre()
     }
+
+    /// A square exactly when its norm is a square in the base field.
+    fn is_square(&self) -> Choice {
+        self.norm().is_square()
+    }
 }
 
 impl Field for Fq2 {
@@ -335,59 +354,34 @@ impl Field for Fq2 {
     }
 
     fn sqrt(&self) -> CtOption<Self> {
-        // Algorithm 9, https://eprint.iacr.org/2012/685.pdf
-
-        if self.is_zero().into() {
-            CtOption::new(Self::ZERO, Choice::from(1))
-        } else {
-            // a1 = self^((q - 3) / 4)
-            // 0xc19139cb84c680a6e14116da060561765e05aa45a1c72a34f082305b61f3f51
-            let u: [u64; 4] = [
-                0x4f082305b61f3f51,
-                0x65e05aa45a1c72a3,
-                0x6e14116da0605617,
-                0x0c19139cb84c680a,
-            ];
-            let mut a1 = self.pow(u);
-            let mut alpha = a1;
-
-            alpha.square_assign();
-            alpha.mul_assign(self);
-            let mut a0 = alpha;
-            a0.frobenius_map(1);
-            a0.mul_assign(&alpha);
-
-            let neg1 = Fq2 {
-                c0: NEGATIVE_ONE,
-                c1: Fq::zero(),
-            };
-
-            if a0 == neg1 {
-                CtOption::new(a0, Choice::from(0))
-            } else {
-                a1.mul_assign(self);
-
-                if alpha == neg1 {
-                    a1.mul_assign(&Fq2 {
-                        c0: Fq::zero(),
-                        c1: Fq::one(),
-                    });
-                } else {
-                    alpha += &Fq2::ONE;
-                    // alpha = alpha^((q - 1) / 2)
-                    // 0x183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea3
-                    let u: [u64; 4] = [
-                        0x9e10460b6c3e7ea3,
-                        0xcbc0b548b438e546,
-                        0xdc2822db40c0ac2e,
-                        0x183227397098d014,
-                    ];
-                    alpha = alpha.pow(u);
-                    a1.mul_assign(&alpha);
-                }
-                CtOption::new(a1, Choice::from(1))
-            }
-        }
+        // Complex method, Algorithm 8 of https://eprint.iacr.org/2012/685.pdf,
+        // which only takes square roots in the base field. With u^2 = -1 the
+        // norm of a0 + a1 u is a0^2 + a1^2.
+        let a0 = self.c0;
+        let a1 = self.c1;
+        let two_inv = <Fq as PrimeField>::TWO_INV;
+
+        // a1 = 0: either a0 or -a0 is a square in Fq, since -1 is not
+        let a0_sqrt = a0.sqrt();
+        let base = Fq2::conditional_select(
+            &Fq2::new(Fq::zero(), (-a0).sqrt().unwrap_or(Fq::zero())),
+            &Fq2::new(a0_sqrt.unwrap_or(Fq::zero()), Fq::zero()),
+            a0_sqrt.is_some(),
+        );
+
+        // a1 != 0: x0 = sqrt((a0 ± sqrt(a0^2 + a1^2)) / 2) and x1 = a1 / (2 x0)
+        let gamma = (a0.square() + a1.square()).sqrt().unwrap_or(Fq::zero());
+        let delta = (a0 + gamma) * two_inv;
+        let delta_sqrt = delta.sqrt();
+        let x0 = Fq::conditional_select(
+            &((a0 - gamma) * two_inv).sqrt().unwrap_or(Fq::zero()),
+            &delta_sqrt.unwrap_or(Fq::zero()),
+            delta_sqrt.is_some(),
+        );
+        let x1 = a1 * x0.double().invert().unwrap_or(Fq::zero());
+
+        let res = Fq2::conditional_select(&Fq2::new(x0, x1), &base, a1.is_zero());
+        CtOption::new(res, res.square().ct_eq(self))
     }
 
     fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
@@ -513,15 +507,19 @@ impl crate::serde::SerdeObject for Fq2 {
         }
         res
     }
+
+    #[cfg(feature = "std")]
     fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
         let [c0, c1] = [(); 2].map(|_| Fq::read_raw_unchecked(reader));
         Self { c0, c1 }
     }
+    #[cfg(feature = "std")]
     fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let c0 = Fq::read_raw(reader)?;
         let c1 = Fq::read_raw(reader)?;
         Ok(Self { c0, c1 })
     }
+    #[cfg(feature = "std")]
     fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         self.c0.write_raw(writer)?;
         self.c1.write_raw(writer)
@@ -548,7 +546,9 @@ mod test {
     crate::field_testing_suite!(Fq2, "conversion");
     crate::field_testing_suite!(Fq2, "serialization");
     crate::field_testing_suite!(Fq2, "quadratic_residue");
+    crate::field_testing_suite!(Fq2, "is_square");
     crate::field_testing_suite!(Fq2, "sqrt");
+    crate::field_testing_suite!(Fq2, "pow_fixed_window");
     crate::field_testing_suite!(Fq2, "zeta", Fq);
     // extension field-specific
     crate::field_testing_suite!(Fq2, "f2_tests", Fq);
@@ -567,6 +567,34 @@ mod test {
         ]
     );
 
+    #[test]
+    fn test_fq2_sqrt_complex() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let mut squares = (0..1000).map(|_| Fq2::random(&mut rng)).collect::<Vec<_>>();
+        // base field elements take the a1 = 0 branch
+        squares.extend((0..100).map(|_| Fq2::new(Fq::random(&mut rng), Fq::zero())));
+        squares.extend([Fq2::zero(), Fq2::one(), Fq2::new(Fq::zero(), Fq::one())]);
+        for a in squares {
+            let a2 = a.square();
+            let root = a2.sqrt().unwrap();
+            assert_eq!(root.square(), a2);
+            assert!(root == a || root == -a);
+        }
+
+        let mut non_squares = 0;
+        for _ in 0..1000 {
+            let a = Fq2::random(&mut rng);
+            let is_square = bool::from(a.legendre().ct_ne(&-1));
+            assert_eq!(bool::from(a.sqrt().is_some()), is_square);
+            non_squares += !is_square as usize;
+        }
+        assert!(non_squares > 0);
+    }
+
     #[test]
     fn test_fq2_squaring() {
         let mut a = Fq2 {
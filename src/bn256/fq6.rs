@@ -261,7 +261,7 @@ impl Fq6 {
 
     /// Multiply by cubic nonresidue v.
     pub fn mul_by_nonresidue(&mut self) {
-        use std::mem::swap;
+        use core::mem::swap;
         swap(&mut self.c0, &mut self.c1);
         swap(&mut self.c0, &mut self.c2);
         // c0, c1, c2 -> c2, c0, c1
@@ -388,6 +388,11 @@ impl Fq6 {
             tmp
         })
     }
+
+    /// Variable time `self^exp` through a one-off [`PowCache`](crate::ff_ext::pow::PowCache).
+    pub fn pow_fixed_window(&self, exp: &[u64], window_bits: usize) -> Self {
+        crate::ff_ext::pow::PowCache::new(self, window_bits).pow(exp)
+    }
 }
 
 impl Field for Fq6 {
@@ -589,6 +594,7 @@ pub const FROBENIUS_COEFF_FQ6_C2: [Fq2; 6] = [
 mod test {
     use super::*;
     crate::field_testing_suite!(Fq6, "field_arithmetic");
+    crate::field_testing_suite!(Fq6, "pow_fixed_window");
     // extension field-specific
     crate::field_testing_suite!(Fq6, "f6_tests", Fq2);
     crate::field_testing_suite!(
@@ -605,4 +611,19 @@ mod test {
             0x30644e72e131a029,
         ]
     );
+    crate::field_testing_suite!(
+        Fq6,
+        "frobenius_coeffs",
+        // p: modulus of base field (Here, Fq::MODULUS)
+        [
+            0x3c208c16d87cfd47,
+            0x97816a916871ca8d,
+            0xb85045b68181585d,
+            0x30644e72e131a029,
+        ],
+        // non_residue = u + 9
+        Fq2::new(Fq::from(9), Fq::one()),
+        [FROBENIUS_COEFF_FQ6_C1, 1, 3],
+        [FROBENIUS_COEFF_FQ6_C2, 2, 3]
+    );
 }
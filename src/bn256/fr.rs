@@ -2,6 +2,7 @@
 use crate::bn256::assembly::field_arithmetic_asm;
 #[cfg(not(feature = "asm"))]
 use crate::{arithmetic::macx, field_arithmetic, field_specific};
+use alloc::{vec, vec::Vec};
 
 #[cfg(feature = "bn256-table")]
 #[rustfmt::skip]
@@ -25,6 +26,7 @@ use crate::{
     field_bits, field_common, impl_add_binop_specify_output, impl_binops_additive,
     impl_binops_additive_specify_output, impl_binops_multiplicative,
     impl_binops_multiplicative_mixed, impl_sub_binop_specify_output, impl_sum_prod,
+    impl_u64_assign_ops,
 };
 use core::convert::TryInto;
 use core::fmt;
@@ -166,6 +168,7 @@ field_common!(
     R3
 );
 impl_sum_prod!(Fr);
+impl_u64_assign_ops!(Fr);
 extend_field_legendre!(Fr);
 
 #[cfg(not(feature = "bn256-table"))]
@@ -204,6 +207,222 @@ impl Fr {
     pub const fn size() -> usize {
         32
     }
+
+    /// Computes the inner product `Σ s_i B_i` of `scalars` with `bases`.
+    ///
+    /// This is a named alias for [`best_multiexp`](crate::msm::best_multiexp)
+    /// and panics if `scalars` and `bases` have a different length.
+    pub fn inner_product_with_bases(
+        scalars: &[Fr],
+        bases: &[crate::bn256::G1Affine],
+    ) -> crate::bn256::G1 {
+        crate::msm::best_multiexp(scalars, bases)
+    }
+
+    /// Converts each of `elems` to its canonical little-endian byte encoding.
+    pub fn to_repr_vec(elems: &[Fr]) -> Vec<[u8; 32]> {
+        elems.iter().map(|e| e.to_repr()).collect()
+    }
+
+    /// Inverse of [`Fr::to_repr_vec`]. Returns `None` if any of `reprs` is
+    /// not a canonical encoding.
+    pub fn from_repr_vec(reprs: &[[u8; 32]]) -> Option<Vec<Fr>> {
+        reprs
+            .iter()
+            .map(|repr| Option::from(Fr::from_repr(*repr)))
+            .collect()
+    }
+
+    /// Returns the canonical big-endian encoding of `self` with leading zero
+    /// bytes removed. Zero encodes as an empty vector.
+    pub fn to_repr_be_trimmed(&self) -> Vec<u8> {
+        let mut repr = self.to_repr();
+        repr.reverse();
+        let leading_zeros = repr.iter().take_while(|b| **b == 0).count();
+        repr[leading_zeros..].to_vec()
+    }
+
+    /// Inverse of [`Fr::to_repr_be_trimmed`]: left-pads `bytes` with zeros to
+    /// 32 bytes and decodes them as a big-endian integer. Fails if `bytes` is
+    /// longer than 32 bytes or encodes a value not less than the modulus.
+    pub fn from_repr_be_trimmed(bytes: &[u8]) -> CtOption<Fr> {
+        if bytes.len() > 32 {
+            return CtOption::new(Fr::zero(), Choice::from(0u8));
+        }
+        let mut repr = [0u8; 32];
+        repr[32 - bytes.len()..].copy_from_slice(bytes);
+        repr.reverse();
+        Fr::from_repr(repr)
+    }
+
+    /// Reduces the 512-bit little endian integer `bytes` modulo $r$, as
+    /// `FromUniformBytes<64>` does, without importing the `ff` trait.
+    ///
+    /// For uniformly random input the result is within statistical distance
+    /// $2^{-258}$ of uniform, so it can be used to derive challenges from a
+    /// 64-byte hash output.
+    pub fn from_bytes_wide(bytes: &[u8; 64]) -> Fr {
+        <Fr as FromUniformBytes<64>>::from_uniform_bytes(bytes)
+    }
+
+    /// Reduces the 256-bit little endian integer `bytes` modulo $r$, and
+    /// returns whether it was out of range, i.e. not less than $r$, in which
+    /// case `from_repr` would have rejected it.
+    pub fn from_bytes_le_reduce_flagged(bytes: &[u8; 32]) -> (Fr, Choice) {
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        let (_, borrow) = sbb(limbs[0], MODULUS.0[0], 0);
+        let (_, borrow) = sbb(limbs[1], MODULUS.0[1], borrow);
+        let (_, borrow) = sbb(limbs[2], MODULUS.0[2], borrow);
+        let (_, borrow) = sbb(limbs[3], MODULUS.0[3], borrow);
+        let out_of_range = Choice::from(1u8 ^ (borrow >> 63) as u8);
+
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(bytes);
+        (Fr::from_bytes_wide(&wide), out_of_range)
+    }
+
+    /// Returns `num / den`, failing if `den` is zero.
+    pub fn from_rational(num: i64, den: i64) -> CtOption<Fr> {
+        let from_i64 = |v: i64| {
+            let abs = Fr::from(v.unsigned_abs());
+            Fr::conditional_select(&abs, &-abs, Choice::from((v < 0) as u8))
+        };
+        from_i64(den).invert().map(|inv| from_i64(num) * inv)
+    }
+
+    /// Returns the inverse of `self` and whether `self` was nonzero, in which
+    /// case the inverse is meaningful. The inverse of zero is returned as zero.
+    ///
+    /// Intended for variable time code where `CtOption` is not needed.
+    pub fn invert_unchecked(&self) -> (Self, bool) {
+        let inv = self.invert();
+        (inv.unwrap_or(Self::zero()), inv.is_some().into())
+    }
+
+    /// Returns the signed Booth recoding of `self` in windows of `window`
+    /// bits, least significant digit first. Digits lie in
+    /// $[-2^{w-1}, 2^{w-1}]$ and satisfy $\sum_i d_i 2^{w i} = self$. These are
+    /// the same digits the MSM uses as bucket indices.
+    ///
+    /// This function will panic if `window` is not in `1..=24`.
+    pub fn to_booth_recoding(&self, window: usize) -> Vec<i32> {
+        assert!((1..=24).contains(&window));
+
+        let repr = self.to_repr();
+        let num_windows = Self::NUM_BITS as usize / window + 1;
+        (0..num_windows)
+            .map(|i| crate::msm::get_booth_index(i, window, repr.as_ref()))
+            .collect()
+    }
+
+    /// Returns the little-endian base-$2^{bits}$ digits of the canonical
+    /// value of `self`, always `ceil(NUM_BITS / bits)` of them.
+    ///
+    /// This function will panic if `bits` is not in `1..=64`.
+    pub fn to_radix_limbs(&self, bits: u32) -> Vec<u64> {
+        assert!((1..=64).contains(&bits));
+
+        let repr = self.to_repr();
+        let bit = |i: u32| (repr[(i / 8) as usize] >> (i % 8)) & 1;
+        let num_limbs = (Self::NUM_BITS + bits - 1) / bits;
+        (0..num_limbs)
+            .map(|limb| {
+                let start = limb * bits;
+                let end = (start + bits).min(Self::NUM_BITS);
+                (start..end)
+                    .rev()
+                    .fold(0u64, |acc, i| (acc << 1) | bit(i) as u64)
+            })
+            .collect()
+    }
+
+    /// Computes the product of powers $\prod_i b_i^{e_i}$ with simultaneous
+    /// exponentiation, sharing one squaring per exponent bit across all
+    /// bases. Exponents are little-endian `u64` limbs.
+    ///
+    /// **This operation is variable time with respect to the exponents.**
+    ///
+    /// This function will panic if `bases` and `exps` have a different length.
+    pub fn field_multiexp(bases: &[Fr], exps: &[[u64; 4]]) -> Fr {
+        assert_eq!(bases.len(), exps.len());
+
+        let mut acc = Fr::one();
+        for i in (0..256).rev() {
+            acc = acc.square();
+            for (base, exp) in bases.iter().zip(exps.iter()) {
+                if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+                    acc *= base;
+                }
+            }
+        }
+        acc
+    }
+
+    /// Reduces each of the 512-bit little-endian `inputs` modulo `r`, as
+    /// [`FromUniformBytes::from_uniform_bytes`] would.
+    ///
+    /// This is a parallel map: each element is reduced on its own, so there is
+    /// no per-element gain over calling `from_uniform_bytes` in a loop. With
+    /// the `parallel` feature the inputs are split into one chunk per thread.
+    pub fn from_uniform_bytes_batch(inputs: &[[u8; 64]]) -> Vec<Fr> {
+        let mut results = vec![Fr::zero(); inputs.len()];
+
+        let reduce = |inputs: &[[u8; 64]], results: &mut [Fr]| {
+            for (input, result) in inputs.iter().zip(results.iter_mut()) {
+                *result = Fr::from_uniform_bytes(input);
+            }
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            let num_threads = rayon::current_num_threads();
+            if inputs.len() > num_threads {
+                let chunk = inputs.len().div_ceil(num_threads);
+                rayon::scope(|scope| {
+                    let reduce = &reduce;
+                    for (inputs, results) in inputs.chunks(chunk).zip(results.chunks_mut(chunk)) {
+                        scope.spawn(move |_| reduce(inputs, results));
+                    }
+                });
+                return results;
+            }
+        }
+
+        reduce(inputs, &mut results);
+        results
+    }
+
+    /// Samples a uniformly random element from the operating system's
+    /// random number generator.
+    ///
+    /// Candidates are drawn as 254-bit integers and rejected until one is
+    /// below the modulus, so the output carries no reduction bias.
+    #[cfg(feature = "getrandom")]
+    pub fn secure_random() -> Fr {
+        use rand_core::OsRng;
+
+        loop {
+            let mut repr = [0u8; 32];
+            OsRng.fill_bytes(&mut repr);
+            repr[31] &= 0x3f;
+            if let Some(e) = Option::from(Fr::from_repr(repr)) {
+                return e;
+            }
+        }
+    }
+}
+
+#[cfg(any(test, feature = "test-vectors"))]
+impl Fr {
+    /// Exponentiates `self` by `exp`, where `exp` is an arbitrary precision integer.
+    ///
+    /// **This operation is variable time with respect to the exponent.**
+    pub fn pow_vartime_biguint(&self, exp: &num_bigint::BigUint) -> Self {
+        ff::Field::pow_vartime(self, exp.to_u64_digits())
+    }
 }
 
 impl ff::Field for Fr {
@@ -326,6 +545,37 @@ impl FromUniformBytes<64> for Fr {
     }
 }
 
+impl FromUniformBytes<48> for Fr {
+    /// Converts a 384-bit little endian integer into
+    /// an `Fr` by reducing by the modulus.
+    fn from_uniform_bytes(bytes: &[u8; 48]) -> Self {
+        let mut wide = [0u8; 64];
+        wide[..48].copy_from_slice(bytes);
+        <Self as FromUniformBytes<64>>::from_uniform_bytes(&wide)
+    }
+}
+
+impl FromUniformBytes<80> for Fr {
+    /// Converts a 640-bit little endian integer into
+    /// an `Fr` by reducing by the modulus.
+    fn from_uniform_bytes(bytes: &[u8; 80]) -> Self {
+        let lo =
+            <Self as FromUniformBytes<64>>::from_uniform_bytes(bytes[0..64].try_into().unwrap());
+        let hi = Self::from_u512([
+            u64::from_le_bytes(bytes[64..72].try_into().unwrap()),
+            u64::from_le_bytes(bytes[72..80].try_into().unwrap()),
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]);
+        // R3 is 2^512 mod r in Montgomery form
+        lo + hi * R3
+    }
+}
+
 impl WithSmallOrderMulGroup<3> for Fr {
     const ZETA: Self = ZETA;
 }
@@ -334,14 +584,37 @@ impl WithSmallOrderMulGroup<3> for Fr {
 mod test {
     use super::*;
     crate::field_testing_suite!(Fr, "field_arithmetic");
+    crate::field_testing_suite!(Fr, "mul_add");
     crate::field_testing_suite!(Fr, "conversion");
     crate::field_testing_suite!(Fr, "serialization");
     crate::field_testing_suite!(Fr, "quadratic_residue");
+    crate::field_testing_suite!(Fr, "is_square");
     crate::field_testing_suite!(Fr, "bits");
+    crate::field_testing_suite!(Fr, "bits_cross_width");
+    crate::field_testing_suite!(Fr, "ct_less_than_power_of_two");
+    crate::field_testing_suite!(Fr, "from_repr_slice");
+    crate::field_testing_suite!(Fr, "serialized_size");
+    crate::field_testing_suite!(Fr, "mul_by_u64");
+    crate::field_testing_suite!(Fr, "conditional_select_slice");
+    crate::field_testing_suite!(Fr, "batch_invert");
+    crate::field_testing_suite!(Fr, "pow_fixed_window");
+    crate::field_testing_suite!(Fr, "try_from_bytes");
+    crate::field_testing_suite!(Fr, "sqrt_alt");
+    crate::field_testing_suite!(Fr, "sub_borrow");
+    crate::field_testing_suite!(Fr, "halve");
+    crate::field_testing_suite!(Fr, "u64_assign_ops");
+    crate::field_testing_suite!(Fr, "raw_vs_from");
+    crate::field_testing_suite!(Fr, "bytes_be");
+    crate::field_testing_suite!(Fr, "pow_ct");
+    crate::field_testing_suite!(Fr, "le_bits_array");
+    crate::field_testing_suite!(Fr, "from_raw_checked");
     crate::field_testing_suite!(Fr, "serialization_check");
     crate::field_testing_suite!(Fr, "constants", MODULUS_STR);
     crate::field_testing_suite!(Fr, "sqrt");
+    crate::field_testing_suite!(Fr, "sqrt_canonical");
     crate::field_testing_suite!(Fr, "zeta");
+    crate::field_testing_suite!(Fr, "from_uniform_bytes", 64);
+    crate::field_testing_suite!(Fr, "from_uniform_bytes", 80);
     crate::field_testing_suite!(
         Fr,
         "from_uniform_bytes",
@@ -409,6 +682,207 @@ mod test {
         ]
     );
 
+    #[test]
+    fn test_inner_product_with_bases() {
+        use crate::bn256::G1;
+        use group::{Curve, Group};
+        use rand_core::OsRng;
+
+        for n in [0, 1, 7, 64, 1000] {
+            let scalars = (0..n).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+            let bases = (0..n)
+                .map(|_| G1::random(OsRng).to_affine())
+                .collect::<Vec<_>>();
+
+            let expected = crate::msm::best_multiexp(&scalars, &bases);
+            assert_eq!(Fr::inner_product_with_bases(&scalars, &bases), expected);
+
+            let naive = scalars
+                .iter()
+                .zip(bases.iter())
+                .fold(G1::identity(), |acc, (s, b)| acc + b * s);
+            assert_eq!(expected, naive);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inner_product_with_bases_length_mismatch() {
+        use crate::bn256::G1Affine;
+
+        let scalars = vec![Fr::one(); 3];
+        let bases = vec![G1Affine::generator(); 2];
+        Fr::inner_product_with_bases(&scalars, &bases);
+    }
+
+    #[test]
+    fn test_repr_vec() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        let elems = (0..1000).map(|_| Fr::random(&mut rng)).collect::<Vec<_>>();
+
+        let reprs = Fr::to_repr_vec(&elems);
+        assert_eq!(reprs.len(), elems.len());
+        for (repr, e) in reprs.iter().zip(elems.iter()) {
+            assert_eq!(*repr, e.to_repr());
+        }
+        assert_eq!(
+            reprs.iter().map(|repr| repr.len()).sum::<usize>(),
+            Fr::serialized_size_of(elems.len())
+        );
+        assert_eq!(Fr::from_repr_vec(&reprs), Some(elems));
+        assert_eq!(Fr::from_repr_vec(&[]), Some(vec![]));
+
+        // the modulus itself is not canonical
+        let mut reprs = reprs;
+        reprs[500] = (-Fr::one()).to_repr();
+        reprs[500][0] += 1;
+        assert_eq!(Fr::from_repr_vec(&reprs), None);
+        reprs[500] = [0xff; 32];
+        assert_eq!(Fr::from_repr_vec(&reprs), None);
+    }
+
+    #[test]
+    fn test_invert_unchecked() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let (inv, was_nonzero) = Fr::zero().invert_unchecked();
+        assert!(!was_nonzero);
+        assert_eq!(inv, Fr::zero());
+
+        for a in [Fr::one(), -Fr::one()]
+            .into_iter()
+            .chain((0..1000).map(|_| Fr::random(&mut rng)))
+            .collect::<Vec<_>>()
+        {
+            let (inv, was_nonzero) = a.invert_unchecked();
+            assert_eq!(was_nonzero, !bool::from(a.is_zero()));
+            assert_eq!(inv * a, Fr::one());
+            assert_eq!(inv, a.invert().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_booth_recoding() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let scalars = [Fr::zero(), Fr::one(), -Fr::one()]
+            .into_iter()
+            .chain((0..100).map(|_| Fr::random(&mut rng)))
+            .collect::<Vec<_>>();
+        for scalar in scalars {
+            for window in 1..=24 {
+                let digits = scalar.to_booth_recoding(window);
+                assert!(digits.iter().all(|d| d.unsigned_abs() <= 1 << (window - 1)));
+
+                // most significant digit first, as for the NAF in the Miller loop
+                let reconstructed = digits.iter().rev().fold(Fr::zero(), |acc, d| {
+                    let acc = acc * Fr::from(1 << window);
+                    match d.signum() {
+                        1 => acc + Fr::from(d.unsigned_abs() as u64),
+                        -1 => acc - Fr::from(d.unsigned_abs() as u64),
+                        _ => acc,
+                    }
+                });
+                assert_eq!(reconstructed, scalar);
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_radix_limbs() {
+        use num_bigint::BigUint;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let elems = [Fr::zero(), Fr::one(), -Fr::one()]
+            .into_iter()
+            .chain((0..100).map(|_| Fr::random(&mut rng)))
+            .collect::<Vec<_>>();
+        for a in elems {
+            let value = BigUint::from_bytes_le(&a.to_repr());
+            for bits in 1..=64 {
+                let limbs = a.to_radix_limbs(bits);
+                assert_eq!(limbs.len() as u32, (Fr::NUM_BITS + bits - 1) / bits);
+                assert!(bits == 64 || limbs.iter().all(|limb| *limb < 1 << bits));
+
+                let recombined = limbs
+                    .iter()
+                    .rev()
+                    .fold(BigUint::from(0u64), |acc, limb| (acc << bits) + limb);
+                assert_eq!(recombined, value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_multiexp() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert_eq!(Fr::field_multiexp(&[], &[]), Fr::one());
+
+        for n in [1, 2, 5, 16] {
+            let bases = (0..n).map(|_| Fr::random(&mut rng)).collect::<Vec<_>>();
+            let exps = (0..n)
+                .map(|_| [(); 4].map(|_| rng.next_u64()))
+                .collect::<Vec<_>>();
+
+            let expected = bases
+                .iter()
+                .zip(exps.iter())
+                .fold(Fr::one(), |acc, (base, exp)| acc * base.pow(exp));
+            assert_eq!(Fr::field_multiexp(&bases, &exps), expected);
+        }
+    }
+
+    #[test]
+    fn test_pow_vartime_biguint() {
+        use num_bigint::BigUint;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        for num_limbs in 0..8 {
+            for _ in 0..100 {
+                let a = Fr::random(&mut rng);
+                let limbs = (0..num_limbs).map(|_| rng.next_u64()).collect::<Vec<_>>();
+                let exp = BigUint::from_bytes_le(
+                    &limbs
+                        .iter()
+                        .flat_map(|limb| limb.to_le_bytes())
+                        .collect::<Vec<_>>(),
+                );
+                assert_eq!(a.pow_vartime_biguint(&exp), a.pow_vartime(&limbs));
+            }
+        }
+
+        // Fermat: a^(r - 1) = 1, with the exponent given in decimal
+        let r_minus_one = BigUint::parse_bytes(
+            b"21888242871839275222246405745257275088548364400416034343698204186575808495616",
+            10,
+        )
+        .unwrap();
+        for _ in 0..100 {
+            let a = Fr::random(&mut rng);
+            assert_eq!(a.pow_vartime_biguint(&r_minus_one), Fr::one());
+        }
+    }
+
     #[test]
     fn bench_fr_from_u16() {
         use ark_std::{end_timer, start_timer};
@@ -422,4 +896,187 @@ mod test {
 
         end_timer!(timer);
     }
+
+    #[test]
+    fn test_from_uniform_bytes_batch() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for n in [0, 1, 7, 1000] {
+            let mut inputs = vec![[0u8; 64]; n];
+            for input in inputs.iter_mut() {
+                rng.fill_bytes(input);
+            }
+            if n > 1 {
+                inputs[0] = [0xff; 64];
+            }
+
+            let batch = Fr::from_uniform_bytes_batch(&inputs);
+            assert_eq!(batch.len(), n);
+            for (input, result) in inputs.iter().zip(batch.iter()) {
+                assert_eq!(*result, Fr::from_uniform_bytes(input));
+            }
+        }
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn test_secure_random() {
+        let samples = (0..100).map(|_| Fr::secure_random()).collect::<Vec<_>>();
+        for (i, a) in samples.iter().enumerate() {
+            // in field: the encoding is canonical
+            assert_eq!(Fr::from_repr(a.to_repr()).unwrap(), *a);
+            for b in samples[i + 1..].iter() {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_repr_be_trimmed() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert!(Fr::zero().to_repr_be_trimmed().is_empty());
+        assert_eq!(Fr::from_repr_be_trimmed(&[]).unwrap(), Fr::zero());
+        assert_eq!(Fr::one().to_repr_be_trimmed(), vec![1]);
+        assert_eq!(Fr::from(0x1234).to_repr_be_trimmed(), vec![0x12, 0x34]);
+        assert_eq!(Fr::from(256).to_repr_be_trimmed(), vec![1, 0]);
+        // leading zeros are accepted on decoding
+        assert_eq!(Fr::from_repr_be_trimmed(&[0, 0, 7]).unwrap(), Fr::from(7));
+
+        let elements = [-Fr::one(), Fr::from(u64::MAX)]
+            .into_iter()
+            .chain((0..100).map(|_| Fr::random(&mut rng)));
+        for a in elements {
+            let bytes = a.to_repr_be_trimmed();
+            assert!(bytes.len() <= 32);
+            assert_ne!(bytes.first(), Some(&0));
+            assert_eq!(Fr::from_repr_be_trimmed(&bytes).unwrap(), a);
+
+            let mut be = a.to_repr();
+            be.reverse();
+            assert!(be.ends_with(&bytes));
+        }
+        assert_eq!((-Fr::one()).to_repr_be_trimmed().len(), 32);
+
+        // the modulus and over-long inputs are rejected
+        let mut modulus = (-Fr::one()).to_repr_be_trimmed();
+        modulus[31] += 1;
+        assert!(bool::from(Fr::from_repr_be_trimmed(&modulus).is_none()));
+        assert!(bool::from(Fr::from_repr_be_trimmed(&[0; 33]).is_none()));
+    }
+
+    #[test]
+    fn test_from_rational() {
+        assert_eq!(Fr::from_rational(1, 3).unwrap() * Fr::from(3), Fr::one());
+        assert_eq!(Fr::from_rational(3, 7).unwrap() * Fr::from(7), Fr::from(3));
+        assert_eq!(
+            Fr::from_rational(-3, 7).unwrap(),
+            -Fr::from_rational(3, 7).unwrap()
+        );
+        assert_eq!(
+            Fr::from_rational(3, -7).unwrap(),
+            Fr::from_rational(-3, 7).unwrap()
+        );
+        assert_eq!(Fr::from_rational(-6, -2).unwrap(), Fr::from(3));
+        assert_eq!(Fr::from_rational(0, 5).unwrap(), Fr::zero());
+        assert_eq!(Fr::from_rational(i64::MIN, 1).unwrap(), -Fr::from(1 << 63));
+
+        for num in [-1, 0, 1, i64::MAX, i64::MIN] {
+            assert!(bool::from(Fr::from_rational(num, 0).is_none()));
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_wide() {
+        use num_bigint::BigUint;
+        use num_traits::Num;
+
+        let modulus = BigUint::from_str_radix(&MODULUS_STR[2..], 16).unwrap();
+        let reference = |bytes: &[u8]| {
+            let mut repr = (BigUint::from_bytes_le(bytes) % &modulus).to_bytes_le();
+            repr.resize(32, 0);
+            Fr::from_repr(repr.try_into().unwrap()).unwrap()
+        };
+
+        // structured inputs: all zeros, all ones, powers of two, byte patterns
+        let mut inputs = vec![[0u8; 64], [0xff; 64], [0xaa; 64], [0x55; 64]];
+        for bit in 0..512 {
+            let mut bytes = [0u8; 64];
+            bytes[bit / 8] = 1 << (bit % 8);
+            inputs.push(bytes);
+            let mut low = [0xff; 64];
+            low[bit / 8..].fill(0);
+            inputs.push(low);
+        }
+
+        for bytes in inputs.iter() {
+            let expected = reference(bytes);
+            assert_eq!(Fr::from_bytes_wide(bytes), expected);
+            assert_eq!(
+                <Fr as FromUniformBytes<64>>::from_uniform_bytes(bytes),
+                expected
+            );
+
+            let short: [u8; 48] = bytes[..48].try_into().unwrap();
+            assert_eq!(
+                <Fr as FromUniformBytes<48>>::from_uniform_bytes(&short),
+                reference(&short)
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_le_reduce_flagged() {
+        use num_bigint::BigUint;
+        use num_traits::Num;
+        use rand_core::OsRng;
+
+        let modulus = BigUint::from_str_radix(&MODULUS_STR[2..], 16).unwrap();
+        let r_minus_one = (-Fr::ONE).to_repr();
+        let add = |repr: [u8; 32], v: u64| {
+            let mut sum = (BigUint::from_bytes_le(&repr) + v).to_bytes_le();
+            sum.resize(32, 0);
+            <[u8; 32]>::try_from(sum).unwrap()
+        };
+
+        // boundary inputs around r, and the largest 256-bit value
+        let mut inputs = vec![[0u8; 32], [0xff; 32], r_minus_one];
+        inputs.extend((1..=2).map(|v| add(r_minus_one, v)));
+        for _ in 0..1000 {
+            let mut bytes = [0u8; 32];
+            OsRng.fill_bytes(&mut bytes);
+            inputs.push(bytes);
+            // mostly in range
+            bytes[31] &= 0x3f;
+            inputs.push(bytes);
+        }
+
+        for bytes in inputs {
+            let (value, out_of_range) = Fr::from_bytes_le_reduce_flagged(&bytes);
+            let int = BigUint::from_bytes_le(&bytes);
+            assert_eq!(bool::from(out_of_range), int >= modulus);
+            assert_eq!(
+                bool::from(out_of_range),
+                bool::from(Fr::from_repr(bytes).is_none())
+            );
+
+            let mut expected = (int % &modulus).to_bytes_le();
+            expected.resize(32, 0);
+            assert_eq!(value.to_repr(), <[u8; 32]>::try_from(expected).unwrap());
+        }
+
+        // r - 1 is in range, r is not and reduces to zero
+        assert!(!bool::from(
+            Fr::from_bytes_le_reduce_flagged(&r_minus_one).1
+        ));
+        let (zero, flag) = Fr::from_bytes_le_reduce_flagged(&add(r_minus_one, 1));
+        assert!(bool::from(flag));
+        assert_eq!(zero, Fr::ZERO);
+    }
 }
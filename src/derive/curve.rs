@@ -75,8 +75,8 @@ macro_rules! new_curve_impl {
                 pub struct [<$name Compressed >](#[cfg_attr(feature = "derive_serde", serde(with = "serde_arrays"))] [u8; [< $name _COMPRESSED_SIZE >]]);
 
                 // Compressed
-                impl std::fmt::Debug for [< $name Compressed >] {
-                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                impl core::fmt::Debug for [< $name Compressed >] {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                         self.0[..].fmt(f)
                     }
                 }
@@ -184,8 +184,8 @@ macro_rules! new_curve_impl {
                 };
                 #[derive(Copy, Clone)]
                 pub struct [< $name Uncompressed >]([u8; [< $name _UNCOMPRESSED_SIZE >]]);
-                    impl std::fmt::Debug for [< $name Uncompressed >] {
-                        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    impl core::fmt::Debug for [< $name Uncompressed >] {
+                        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                             self.0[..].fmt(f)
                         }
                     }
@@ -244,6 +244,10 @@ macro_rules! new_curve_impl {
                             let y = {
                                 let mut tmp = [0; $base::size()];
                                 tmp.copy_from_slice(&bytes[$base::size()..2*$base::size()]);
+                                // Without an extra byte the infinity flag lives in the top byte of y
+                                if !$flags_extra_byte {
+                                    tmp[$base::size() - 1] &= 0b1011_1111;
+                                }
                                 $base::from_bytes(&tmp)
                             };
 
@@ -360,7 +364,7 @@ macro_rules! new_curve_impl {
             pub z: $base,
         }
 
-        #[derive(Copy, Clone, PartialEq)]
+        #[derive(Copy, Clone)]
         $($privacy)* struct $name_affine {
             pub x: $base,
             pub y: $base,
@@ -401,6 +405,105 @@ macro_rules! new_curve_impl {
             }
         }
 
+        // The isogenous curves are crate-private and only serve hashing to
+        // curve, so only public curves get these helpers.
+        macro_rules! impl_public_helpers {
+            ((pub)) => {
+                impl $name {
+                    /// Plain most-significant-bit-first double-and-add scalar multiplication.
+                    ///
+                    /// This is a variable time reference for auditing the optimized `Mul`
+                    /// implementation and must not be used with secret scalars.
+                    #[cfg(any(test, feature = "reference-impls"))]
+                    #[cfg_attr(docsrs, doc(cfg(feature = "reference-impls")))]
+                    pub fn mul_reference(&self, scalar: &$scalar) -> Self {
+                        let mut acc = $name::identity();
+                        for byte in scalar.to_repr().as_ref().iter().rev() {
+                            for i in (0..8).rev() {
+                                acc = acc.double();
+                                if (byte >> i) & 1 == 1 {
+                                    acc += self;
+                                }
+                            }
+                        }
+                        acc
+                    }
+
+                    /// Multiplies `self` by each of the `scalars`.
+                    ///
+                    /// The multiples `d * 16^j * self` of the shared base for every
+                    /// 4-bit digit `d` and position `j` are computed once, so each
+                    /// product costs one addition per digit and no doublings. Digits
+                    /// are looked up in constant time.
+                    pub fn mul_by_scalar_slice(&self, scalars: &[$scalar]) -> alloc::vec::Vec<Self> {
+                        const WINDOW: usize = 4;
+                        let num_windows = ($scalar::NUM_BITS as usize + WINDOW - 1) / WINDOW;
+
+                        let mut tables: alloc::vec::Vec<alloc::vec::Vec<Self>> = alloc::vec::Vec::with_capacity(num_windows);
+                        let mut base = *self;
+                        for _ in 0..num_windows {
+                            let table: alloc::vec::Vec<Self> =
+                                core::iter::successors(Some($name::identity()), |p| Some(*p + base))
+                                    .take(1 << WINDOW)
+                                    .collect();
+                            base += table[(1 << WINDOW) - 1];
+                            tables.push(table);
+                        }
+
+                        scalars
+                            .iter()
+                            .map(|scalar| {
+                                let repr = scalar.to_repr();
+                                let repr = repr.as_ref();
+                                tables.iter().enumerate().fold($name::identity(), |acc, (i, table)| {
+                                    let digit = (repr[i / 2] >> (WINDOW * (i % 2))) & 0xf;
+                                    let p = table.iter().enumerate().fold($name::identity(), |p, (d, q)| {
+                                        let is_digit = subtle::ConstantTimeEq::ct_eq(&(d as u8), &digit);
+                                        $name::conditional_select(&p, q, is_digit)
+                                    });
+                                    acc + p
+                                })
+                            })
+                            .collect()
+                    }
+
+                    /// Returns `[base * start, base * start * ratio, ..., base * start * ratio^(n - 1)]`.
+                    ///
+                    /// The scalars are built incrementally and multiplied with
+                    /// [`Self::mul_by_scalar_slice`], sharing the precomputation on `base`.
+                    pub fn mul_by_geometric(
+                        base: &Self,
+                        start: &$scalar,
+                        ratio: &$scalar,
+                        n: usize,
+                    ) -> alloc::vec::Vec<Self> {
+                        let scalars: alloc::vec::Vec<$scalar> = core::iter::successors(Some(*start), |s| Some(*s * ratio))
+                            .take(n)
+                            .collect();
+                        base.mul_by_scalar_slice(&scalars)
+                    }
+                }
+
+                impl $name_affine {
+                    /// Constructs a point from affine coordinates, returning `None`
+                    /// unless they satisfy the curve equation. As everywhere in this
+                    /// crate, `(0, 0)` is the identity.
+                    pub fn from_xy(x: $base, y: $base) -> CtOption<Self> {
+                        <Self as $crate::CurveAffine>::from_xy(x, y)
+                    }
+
+                    /// Constructs a point from affine coordinates without checking
+                    /// that they satisfy the curve equation. Only use this for
+                    /// coordinates that are already known to be valid.
+                    pub const fn from_xy_unchecked(x: $base, y: $base) -> Self {
+                        $name_affine { x, y }
+                    }
+                }
+            };
+            ($other:tt) => {};
+        }
+        impl_public_helpers!(($($privacy)*));
+
         impl $name_affine {
             pub fn generator() -> Self {
                 Self {
@@ -531,7 +634,7 @@ macro_rules! new_curve_impl {
 
 
             #[allow(clippy::redundant_closure_call)]
-            fn hash_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
+            fn hash_to_curve<'a>(domain_prefix: &'a str) -> alloc::boxed::Box<dyn Fn(&[u8]) -> Self + 'a> {
                 $hash_to_curve($curve_id, domain_prefix)
             }
 
@@ -580,36 +683,38 @@ macro_rules! new_curve_impl {
 
             type AffineRepr = $name_affine;
 
+            /// Converts `p` to affine form with a single field inversion.
+            ///
+            /// Points at infinity (`z == 0`) take part in the batch inversion with
+            /// `z` replaced by one, so the inversion never sees a zero, and their
+            /// outputs are then masked to exactly `identity()`.
             fn batch_normalize(p: &[Self], q: &mut [Self::AffineRepr]) {
                 assert_eq!(p.len(), q.len());
 
+                let z = |p: &Self| $base::conditional_select(&p.z, &$base::one(), p.is_identity());
+
                 let mut acc = $base::one();
                 for (p, q) in p.iter().zip(q.iter_mut()) {
                     // We use the `x` field of $name_affine to store the product
                     // of previous z-coordinates seen.
                     q.x = acc;
-
-                    // We will end up skipping all identities in p
-                    acc = $base::conditional_select(&(acc * p.z), &acc, p.is_identity());
+                    acc *= z(p);
                 }
 
-                // This is the inverse, as all z-coordinates are nonzero and the ones
-                // that are not are skipped.
+                // This is the inverse, as all z-coordinates in the product are nonzero.
                 acc = acc.invert().unwrap();
 
                 for (p, q) in p.iter().rev().zip(q.iter_mut().rev()) {
-                    let skip = p.is_identity();
-
                     // Compute tmp = 1/z
                     let tmp = q.x * acc;
 
                     // Cancel out z-coordinate in denominator of `acc`
-                    acc = $base::conditional_select(&(acc * p.z), &acc, skip);
+                    acc *= z(p);
 
                     q.x = p.x * tmp;
                     q.y = p.y * tmp;
 
-                    *q = $name_affine::conditional_select(&q, &$name_affine::identity(), skip);
+                    *q = $name_affine::conditional_select(&q, &$name_affine::identity(), p.is_identity());
                 }
             }
 
@@ -741,25 +846,31 @@ macro_rules! new_curve_impl {
                     bool::from(res.is_on_curve()).then(|| res)
                 })
             }
-            fn to_raw_bytes(&self) -> Vec<u8> {
-                let mut res = Vec::with_capacity(3 * $base::size());
-                Self::write_raw(self, &mut res).unwrap();
+            fn to_raw_bytes(&self) -> alloc::vec::Vec<u8> {
+                let mut res = alloc::vec::Vec::with_capacity(3 * $base::size());
+                for coord in [self.x, self.y, self.z] {
+                    res.extend_from_slice(&coord.to_raw_bytes());
+                }
                 res
             }
+
+            #[cfg(feature = "std")]
             fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
-                let [x, y, z] = [(); 3].map(|_| $base::read_raw_unchecked(reader));
+                let [x, y, z] = [(); 3].map(|_| <$base as $crate::serde::SerdeObject>::read_raw_unchecked(reader));
                 Self { x, y, z }
             }
+            #[cfg(feature = "std")]
             fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-                let x = $base::read_raw(reader)?;
-                let y = $base::read_raw(reader)?;
-                let z = $base::read_raw(reader)?;
+                let x = <$base as $crate::serde::SerdeObject>::read_raw(reader)?;
+                let y = <$base as $crate::serde::SerdeObject>::read_raw(reader)?;
+                let z = <$base as $crate::serde::SerdeObject>::read_raw(reader)?;
                 Ok(Self { x, y, z })
             }
+            #[cfg(feature = "std")]
             fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-                self.x.write_raw(writer)?;
-                self.y.write_raw(writer)?;
-                self.z.write_raw(writer)
+                $crate::serde::SerdeObject::write_raw(&self.x, writer)?;
+                $crate::serde::SerdeObject::write_raw(&self.y, writer)?;
+                $crate::serde::SerdeObject::write_raw(&self.z, writer)
             }
         }
 
@@ -775,8 +886,8 @@ macro_rules! new_curve_impl {
 
         // Affine implementations
 
-        impl std::fmt::Debug for $name_affine {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        impl core::fmt::Debug for $name_affine {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
                 if self.is_identity().into() {
                     write!(f, "Infinity")
                 } else {
@@ -821,6 +932,15 @@ macro_rules! new_curve_impl {
             }
         }
 
+        impl PartialEq for $name_affine {
+            /// Compares through `ct_eq`, so every representation of the point at
+            /// infinity is equal to every other.
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.ct_eq(other).into()
+            }
+        }
+
         impl cmp::Eq for $name_affine {}
 
 
@@ -842,23 +962,29 @@ macro_rules! new_curve_impl {
                     bool::from(res.is_on_curve()).then(|| res)
                 })
             }
-            fn to_raw_bytes(&self) -> Vec<u8> {
-                let mut res = Vec::with_capacity(2 * $base::size());
-                Self::write_raw(self, &mut res).unwrap();
+            fn to_raw_bytes(&self) -> alloc::vec::Vec<u8> {
+                let mut res = alloc::vec::Vec::with_capacity(2 * $base::size());
+                for coord in [self.x, self.y] {
+                    res.extend_from_slice(&coord.to_raw_bytes());
+                }
                 res
             }
+
+            #[cfg(feature = "std")]
             fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
-                let [x, y] = [(); 2].map(|_| $base::read_raw_unchecked(reader));
+                let [x, y] = [(); 2].map(|_| <$base as $crate::serde::SerdeObject>::read_raw_unchecked(reader));
                 Self { x, y }
             }
+            #[cfg(feature = "std")]
             fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-                let x = $base::read_raw(reader)?;
-                let y = $base::read_raw(reader)?;
+                let x = <$base as $crate::serde::SerdeObject>::read_raw(reader)?;
+                let y = <$base as $crate::serde::SerdeObject>::read_raw(reader)?;
                 Ok(Self { x, y })
             }
+            #[cfg(feature = "std")]
             fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-                self.x.write_raw(writer)?;
-                self.y.write_raw(writer)
+                $crate::serde::SerdeObject::write_raw(&self.x, writer)?;
+                $crate::serde::SerdeObject::write_raw(&self.y, writer)
             }
         }
 
@@ -170,22 +170,310 @@ macro_rules! field_common {
 
             /// Converts from an integer represented in little endian
             /// into its (congruent) `$field` representation.
+            ///
+            /// The input must be less than the modulus, which is checked in
+            /// debug builds only.
             pub const fn from_raw(val: [u64; 4]) -> Self {
+                debug_assert!(Self::is_less_than(&val, &$modulus.0));
                 Self::montgomery_form(val, $r2)
             }
 
+            /// Like [`Self::from_raw`], but checks that the input is less
+            /// than the modulus in every build.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `val` is not less than the modulus. When evaluated
+            /// in a `const` or `static` item, this is a compile-time error.
+            pub const fn from_raw_checked(val: [u64; 4]) -> Self {
+                assert!(
+                    Self::is_less_than(&val, &$modulus.0),
+                    "input is not less than the modulus"
+                );
+                Self::montgomery_form(val, $r2)
+            }
+
+            /// Length in bytes of the canonical encoding, as given by `size()`.
+            pub const SERIALIZED_SIZE: usize = 32;
+
+            /// Length in bytes of the canonical encodings of `n` elements.
+            pub const fn serialized_size_of(n: usize) -> usize {
+                n * Self::SERIALIZED_SIZE
+            }
+
             /// Attempts to convert a little-endian byte representation of
             /// a scalar into a `Fr`, failing if the input is not canonical.
             pub fn from_bytes(bytes: &[u8; 32]) -> CtOption<$field> {
                 <Self as ff::PrimeField>::from_repr(*bytes)
             }
 
+            /// Like [`Self::from_bytes`], but reads from a slice, failing if
+            /// it is not exactly 32 bytes long.
+            pub fn from_repr_slice(bytes: &[u8]) -> CtOption<$field> {
+                match <[u8; 32]>::try_from(bytes) {
+                    Ok(bytes) => Self::from_bytes(&bytes),
+                    Err(_) => CtOption::new(Self::zero(), Choice::from(0u8)),
+                }
+            }
+
             /// Converts an element of `Fr` into a byte representation in
             /// little-endian byte order.
             pub fn to_bytes(&self) -> [u8; 32] {
                 <Self as ff::PrimeField>::to_repr(self)
             }
 
+            /// Returns the bits of the canonical representation of this element,
+            /// least significant first, in the bit order of `to_repr`.
+            ///
+            /// Unlike `PrimeFieldBits::to_le_bits`, this does not need the
+            /// `bits` feature and its `bitvec` dependency.
+            pub fn to_le_bits_array(
+                &self,
+            ) -> [bool; <$field as ff::PrimeField>::NUM_BITS as usize] {
+                let repr = self.to_bytes();
+                let mut bits = [false; <$field as ff::PrimeField>::NUM_BITS as usize];
+                for (i, bit) in bits.iter_mut().enumerate() {
+                    *bit = (repr[i / 8] >> (i % 8)) & 1 == 1;
+                }
+                bits
+            }
+
+            /// Raises this element to the power `exp`, given as little-endian
+            /// limbs.
+            ///
+            /// **This operation is constant time with respect to the
+            /// exponent.** It is `ff::Field::pow`, which is constant time for
+            /// exponents of a fixed number of limbs, here always four, so all
+            /// 256 bits are processed, leading zeros included. Use
+            /// `pow_vartime` for public exponents.
+            pub fn pow_ct(&self, exp: &[u64; 4]) -> Self {
+                ff::Field::pow(self, exp)
+            }
+
+            /// Attempts to convert a big-endian byte representation of a
+            /// scalar into a `$field`, failing if the input is not canonical.
+            pub fn from_bytes_be(bytes: &[u8; 32]) -> CtOption<$field> {
+                let mut repr = *bytes;
+                repr.reverse();
+                Self::from_bytes(&repr)
+            }
+
+            /// Converts an element of `$field` into a byte representation in
+            /// big-endian byte order, the reverse of [`Self::to_bytes`].
+            pub fn to_bytes_be(&self) -> [u8; 32] {
+                let mut bytes = self.to_bytes();
+                bytes.reverse();
+                bytes
+            }
+
+            /// Inverts every nonzero element of `elements` in place with
+            /// Montgomery's trick, using a single field inversion. Zero elements
+            /// are skipped and left unchanged. Returns the product of the
+            /// inverses of the nonzero elements.
+            pub fn batch_invert(elements: &mut [Self]) -> CtOption<Self> {
+                let mut acc = Self::one();
+                let mut prefix = alloc::vec::Vec::with_capacity(elements.len());
+                for element in elements.iter() {
+                    prefix.push(acc);
+                    acc = Self::conditional_select(
+                        &(acc * element),
+                        &acc,
+                        ff::Field::is_zero(element),
+                    );
+                }
+
+                // `acc` is a product of nonzero elements
+                acc.invert().map(|acc_inv| {
+                    let mut acc = acc_inv;
+                    for (element, prefix) in elements.iter_mut().zip(prefix).rev() {
+                        let is_zero = ff::Field::is_zero(element);
+                        let inv = acc * prefix;
+                        acc = Self::conditional_select(&(acc * *element), &acc, is_zero);
+                        *element = Self::conditional_select(&inv, element, is_zero);
+                    }
+                    acc_inv
+                })
+            }
+
+            /// Selects elementwise between `a` and `b` in constant time, returning
+            /// `a` if `choice` is unset and `b` if it is set.
+            ///
+            /// This function will panic if `a` and `b` have a different length.
+            pub fn conditional_select_slice(
+                a: &[Self],
+                b: &[Self],
+                choice: Choice,
+            ) -> alloc::vec::Vec<Self> {
+                assert_eq!(a.len(), b.len());
+                a.iter()
+                    .zip(b.iter())
+                    .map(|(a, b)| Self::conditional_select(a, b, choice))
+                    .collect()
+            }
+
+            /// Computes `3 * self` with one doubling and one addition.
+            #[inline]
+            pub fn mul_by_3(&self) -> Self {
+                self.double() + self
+            }
+
+            /// Computes `k * self` by doubling and adding, which is cheaper than
+            /// a full multiplication for small `k`.
+            ///
+            /// **This operation is variable time with respect to `k`.**
+            pub fn mul_by_u64(&self, k: u64) -> Self {
+                if k == 0 {
+                    return Self::zero();
+                }
+
+                let mut acc = *self;
+                for i in (0..63 - k.leading_zeros()).rev() {
+                    acc = acc.double();
+                    if (k >> i) & 1 == 1 {
+                        acc += self;
+                    }
+                }
+                acc
+            }
+
+            /// Variable time `self^exp` through a one-off [`PowCache`](crate::ff_ext::pow::PowCache).
+            pub fn pow_fixed_window(&self, exp: &[u64], window_bits: usize) -> Self {
+                $crate::ff_ext::pow::PowCache::new(self, window_bits).pow(exp)
+            }
+
+            /// Computes `self * b + c` with a single Montgomery reduction.
+            pub fn mul_add(&self, b: &Self, c: &Self) -> Self {
+                // `c` is in Montgomery form, i.e. `cR`. Accumulating it into the upper
+                // half of the 512-bit product gives `abR^2 + cR^2`, which reduces to
+                // `(ab + c)R` as desired.
+                let t = $crate::arithmetic::mul_512(self.0, b.0);
+                let (t4, carry) = adc(t[4], c.0[0], 0);
+                let (t5, carry) = adc(t[5], c.0[1], carry);
+                let (t6, carry) = adc(t[6], c.0[2], carry);
+                let (t7, t8) = adc(t[7], c.0[3], carry);
+                let mut r = [t[0], t[1], t[2], t[3], t4, t5, t6, t7, t8];
+
+                // Montgomery reduction, keeping track of the extra top limb.
+                for i in 0..4 {
+                    let k = r[i].wrapping_mul($inv);
+                    let mut carry = 0;
+                    for j in 0..4 {
+                        (r[i + j], carry) = mac(r[i + j], k, $modulus.0[j], carry);
+                    }
+                    for limb in r[i + 4..].iter_mut() {
+                        (*limb, carry) = adc(*limb, 0, carry);
+                    }
+                }
+
+                // The input is below `p^2 + pR`, so the reduced value is below `3p`
+                // and at most two subtractions of the modulus are needed.
+                let mut d = [r[4], r[5], r[6], r[7]];
+                let mut hi = r[8];
+                for _ in 0..2 {
+                    let (d0, borrow) = sbb(d[0], $modulus.0[0], 0);
+                    let (d1, borrow) = sbb(d[1], $modulus.0[1], borrow);
+                    let (d2, borrow) = sbb(d[2], $modulus.0[2], borrow);
+                    let (d3, borrow) = sbb(d[3], $modulus.0[3], borrow);
+                    let (h, borrow) = sbb(hi, 0, borrow);
+
+                    // Keep the old value if the subtraction underflowed.
+                    let underflow = Choice::from((borrow >> 63) as u8);
+                    d = [
+                        u64::conditional_select(&d0, &d[0], underflow),
+                        u64::conditional_select(&d1, &d[1], underflow),
+                        u64::conditional_select(&d2, &d[2], underflow),
+                        u64::conditional_select(&d3, &d[3], underflow),
+                    ];
+                    hi = u64::conditional_select(&h, &hi, underflow);
+                }
+                debug_assert_eq!(hi, 0);
+
+                $field(d)
+            }
+
+            /// Returns whether the canonical value of `self` is below `2^bits`.
+            ///
+            /// This is constant time with respect to `self`, but not `bits`.
+            pub fn ct_less_than_power_of_two(&self, bits: u32) -> Choice {
+                let repr = self.to_repr();
+                let mut high = 0u64;
+                for (i, chunk) in repr.as_ref().chunks(8).enumerate() {
+                    let limb = u64::from_le_bytes(chunk.try_into().unwrap());
+                    // bits of this limb that are at or above `2^bits`
+                    let low_bits = bits.saturating_sub(64 * i as u32);
+                    let mask = if low_bits >= 64 {
+                        0
+                    } else {
+                        u64::MAX << low_bits
+                    };
+                    high |= limb & mask;
+                }
+                high.ct_eq(&0)
+            }
+
+            /// Returns whether the canonical value of `self` is greater than `(p - 1) / 2`.
+            pub fn lexicographically_largest(&self) -> Choice {
+                let repr = self.to_repr();
+                let mut borrow = 0;
+                for (i, chunk) in repr.as_ref().chunks(8).enumerate() {
+                    let limb = u64::from_le_bytes(chunk.try_into().unwrap());
+                    // (p - 1) / 2 = p >> 1 as p is odd
+                    let half = ($modulus.0[i] >> 1) | $modulus.0.get(i + 1).map_or(0, |l| l << 63);
+                    (_, borrow) = sbb(half, limb, borrow);
+                }
+                // `(p - 1) / 2 - self` underflows iff `self` is the larger root
+                Choice::from((borrow >> 63) as u8)
+            }
+
+            /// Returns `self - rhs` together with whether the subtraction wrapped
+            /// around the modulus, i.e. whether the canonical value of `self` is
+            /// less than that of `rhs`.
+            pub fn sub_borrow(&self, rhs: &Self) -> (Self, Choice) {
+                let (a, b) = (self.to_repr(), rhs.to_repr());
+                let mut borrow = 0;
+                for (a, b) in a.as_ref().chunks(8).zip(b.as_ref().chunks(8)) {
+                    let a = u64::from_le_bytes(a.try_into().unwrap());
+                    let b = u64::from_le_bytes(b.try_into().unwrap());
+                    (_, borrow) = sbb(a, b, borrow);
+                }
+                (self - rhs, Choice::from((borrow >> 63) as u8))
+            }
+
+            /// Returns `self / 2` without a multiplication by `TWO_INV`.
+            ///
+            /// Halving commutes with the Montgomery factor, so the limbs are
+            /// shifted right directly, adding the modulus first when they are odd.
+            pub const fn halve(&self) -> Self {
+                let mask = 0u64.wrapping_sub(self.0[0] & 1);
+                let (d0, carry) = adc(self.0[0], $modulus.0[0] & mask, 0);
+                let (d1, carry) = adc(self.0[1], $modulus.0[1] & mask, carry);
+                let (d2, carry) = adc(self.0[2], $modulus.0[2] & mask, carry);
+                let (d3, carry) = adc(self.0[3], $modulus.0[3] & mask, carry);
+                $field([
+                    (d0 >> 1) | (d1 << 63),
+                    (d1 >> 1) | (d2 << 63),
+                    (d2 >> 1) | (d3 << 63),
+                    (d3 >> 1) | (carry << 63),
+                ])
+            }
+
+            /// Returns the square root of `self` whose canonical value is at most
+            /// `(p - 1) / 2`, if it exists.
+            pub fn sqrt_canonical(&self) -> CtOption<Self> {
+                ff::Field::sqrt(self).map(|root| {
+                    Self::conditional_select(&root, &-root, root.lexicographically_largest())
+                })
+            }
+
+            /// Returns both square roots `(r, -r)` of `self`, if they exist,
+            /// where `r` is the even one, i.e. its canonical value is even.
+            pub fn sqrt_alt(&self) -> CtOption<(Self, Self)> {
+                let root = ff::Field::sqrt(self);
+                let r = root.unwrap_or(Self::zero());
+                let r = Self::conditional_select(&r, &-r, ff::PrimeField::is_odd(&r));
+                CtOption::new((r, -r), root.is_some())
+            }
+
             /// Lexicographic comparison of Montgomery forms.
             #[inline(always)]
             const fn is_less_than(x: &[u64; 4], y: &[u64; 4]) -> bool {
@@ -329,6 +617,16 @@ macro_rules! field_common {
             }
         }
 
+        impl TryFrom<[u8; 32]> for $field {
+            type Error = $crate::ff_ext::InvalidFieldElement;
+
+            /// Decodes a canonical little-endian encoding, failing if `bytes`
+            /// is not less than the modulus.
+            fn try_from(bytes: [u8; 32]) -> Result<Self, Self::Error> {
+                Option::from(Self::from_repr(bytes)).ok_or($crate::ff_ext::InvalidFieldElement)
+            }
+        }
+
         impl $crate::serde::SerdeObject for $field {
             fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
                 debug_assert_eq!(bytes.len(), 32);
@@ -343,13 +641,15 @@ macro_rules! field_common {
                 let elt = Self::from_raw_bytes_unchecked(bytes);
                 Self::is_less_than(&elt.0, &$modulus.0).then(|| elt)
             }
-            fn to_raw_bytes(&self) -> Vec<u8> {
-                let mut res = Vec::with_capacity(32);
+            fn to_raw_bytes(&self) -> alloc::vec::Vec<u8> {
+                let mut res = alloc::vec::Vec::with_capacity(32);
                 for limb in self.0.iter() {
                     res.extend_from_slice(&limb.to_le_bytes());
                 }
                 res
             }
+
+            #[cfg(feature = "std")]
             fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
                 let inner = [(); 4].map(|_| {
                     let mut buf = [0; 8];
@@ -358,6 +658,7 @@ macro_rules! field_common {
                 });
                 Self(inner)
             }
+            #[cfg(feature = "std")]
             fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
                 let mut inner = [0u64; 4];
                 for limb in inner.iter_mut() {
@@ -375,6 +676,7 @@ macro_rules! field_common {
                         )
                     })
             }
+            #[cfg(feature = "std")]
             fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
                 for limb in self.0.iter() {
                     writer.write_all(&limb.to_le_bytes())?;
@@ -181,3 +181,29 @@ macro_rules! impl_sum_prod {
         }
     };
 }
+
+#[macro_export]
+macro_rules! impl_u64_assign_ops {
+    ($f:ident) => {
+        impl ::core::ops::AddAssign<u64> for $f {
+            #[inline]
+            fn add_assign(&mut self, rhs: u64) {
+                *self += $f::from(rhs);
+            }
+        }
+
+        impl ::core::ops::SubAssign<u64> for $f {
+            #[inline]
+            fn sub_assign(&mut self, rhs: u64) {
+                *self -= $f::from(rhs);
+            }
+        }
+
+        impl ::core::ops::MulAssign<u64> for $f {
+            #[inline]
+            fn mul_assign(&mut self, rhs: u64) {
+                *self *= $f::from(rhs);
+            }
+        }
+    };
+}
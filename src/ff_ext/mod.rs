@@ -1,7 +1,22 @@
 pub mod inverse;
 pub mod jacobi;
+pub mod pow;
 use subtle::{Choice, ConstantTimeEq};
 
+/// Error returned when converting bytes that are not the canonical encoding
+/// of a field element, i.e. that encode an integer not less than the modulus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidFieldElement;
+
+impl core::fmt::Display for InvalidFieldElement {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "bytes are not a canonical field element encoding")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidFieldElement {}
+
 pub trait Legendre {
     fn legendre(&self) -> i64;
 
@@ -17,6 +32,16 @@ pub trait Legendre {
         // we consider 0 a square hence quadratic residue.
         self.legendre().ct_ne(&-1)
     }
+
+    /// Returns true if `self` is a square, zero included, matching
+    /// `sqrt().is_some()` without computing the root.
+    ///
+    /// The default goes through [`Legendre::legendre`]. The prime field impls
+    /// of [`extend_field_legendre`](crate::extend_field_legendre) override
+    /// it with a fixed exponentiation that is constant time.
+    fn is_square(&self) -> Choice {
+        self.ct_quadratic_residue()
+    }
 }
 
 #[macro_export]
@@ -27,6 +52,25 @@ macro_rules! extend_field_legendre {
             fn legendre(&self) -> i64 {
                 self.jacobi()
             }
+
+            fn is_square(&self) -> subtle::Choice {
+                use subtle::ConstantTimeEq;
+                use $crate::ff::{Field, PrimeField};
+
+                // Euler's criterion: self^((p - 1) / 2) is 1 for nonzero
+                // squares, -1 for non-squares and 0 for zero.
+                let p_minus_one = (-$field::ONE).to_repr();
+                let mut exp: alloc::vec::Vec<u64> = p_minus_one
+                    .as_ref()
+                    .chunks(8)
+                    .map(|limb| u64::from_le_bytes(limb.try_into().unwrap()))
+                    .collect();
+                for i in 0..exp.len() {
+                    let next = exp.get(i + 1).copied().unwrap_or(0);
+                    exp[i] = (exp[i] >> 1) | (next << 63);
+                }
+                !self.pow(exp).ct_eq(&-$field::ONE)
+            }
         }
     };
 }
@@ -0,0 +1,94 @@
+//! Fixed-base exponentiation with a precomputed window table.
+
+use alloc::vec::Vec;
+use ff::Field;
+
+/// Precomputed powers of a fixed base for repeated exponentiation.
+///
+/// The cache stores $base^0, \ldots, base^{2^w - 1}$ for a window size $w$,
+/// so that each `pow` costs one squaring per exponent bit plus one
+/// multiplication per window, scanning the exponent in windows of $w$ bits.
+///
+/// **This operation is not constant time**: the table lookups depend on the
+/// exponent. The `pow_fixed_window` methods of the fields build a cache for a
+/// single exponentiation; for a base reused across many exponents, build the
+/// cache once instead.
+#[derive(Clone, Debug)]
+pub struct PowCache<F: Field> {
+    window: usize,
+    table: Vec<F>,
+}
+
+impl<F: Field> PowCache<F> {
+    /// Precomputes the powers of `base` for windows of `window` bits.
+    ///
+    /// This function will panic if `window` is not in `1..=16`.
+    pub fn new(base: &F, window: usize) -> Self {
+        assert!((1..=16).contains(&window));
+
+        let table = core::iter::successors(Some(F::ONE), |acc| Some(*acc * base))
+            .take(1 << window)
+            .collect();
+
+        PowCache { window, table }
+    }
+
+    /// Returns the base raised to `exp`, given as little-endian `u64` limbs.
+    pub fn pow<S: AsRef<[u64]>>(&self, exp: S) -> F {
+        let exp = exp.as_ref();
+        let num_bits = exp.len() * 64;
+        let bit = |i: usize| ((exp[i / 64] >> (i % 64)) & 1) as usize;
+
+        let mut res = F::ONE;
+        let mut top = num_bits;
+        while top > 0 {
+            let width = if top % self.window == 0 {
+                self.window
+            } else {
+                top % self.window
+            };
+            let digit = (top - width..top)
+                .rev()
+                .fold(0, |digit, i| (digit << 1) | bit(i));
+
+            for _ in 0..width {
+                res = res.square();
+            }
+            if digit != 0 {
+                res *= self.table[digit];
+            }
+            top -= width;
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::{Fq, Fr};
+    use rand_core::{OsRng, RngCore};
+
+    fn pow_cache_consistency<F: Field>() {
+        let base = F::random(OsRng);
+        for window in [1, 3, 4, 5, 8] {
+            let cache = PowCache::new(&base, window);
+            assert_eq!(cache.pow([]), F::ONE);
+            assert_eq!(cache.pow([0, 0]), F::ONE);
+            assert_eq!(cache.pow([1]), base);
+
+            for n in 1..=5 {
+                for _ in 0..10 {
+                    let exp: Vec<u64> = (0..n).map(|_| OsRng.next_u64()).collect();
+                    assert_eq!(cache.pow(&exp), base.pow_vartime(&exp));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_cache() {
+        pow_cache_consistency::<Fr>();
+        pow_cache_consistency::<Fq>();
+    }
+}
@@ -1,5 +1,6 @@
 pub use crate::{CurveAffine, CurveExt};
-use ff::Field;
+use alloc::vec::Vec;
+use ff::{BatchInvert, Field, PrimeField};
 use group::{GroupOpsOwned, ScalarMulOwned};
 
 /// This represents an element of a group with basic operations that can be
@@ -37,7 +38,10 @@ pub fn best_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar,
         r
     }
 
+    #[cfg(feature = "parallel")]
     let threads = rayon::current_num_threads();
+    #[cfg(not(feature = "parallel"))]
+    let threads = 1_usize;
     let log_threads = threads.ilog2();
     let n = a.len();
     assert_eq!(n, 1 << log_n);
@@ -50,13 +54,7 @@ pub fn best_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar,
     }
 
     // precompute twiddle factors
-    let twiddles: Vec<_> = (0..(n / 2))
-        .scan(Scalar::ONE, |w, _| {
-            let tw = *w;
-            *w *= &omega;
-            Some(tw)
-        })
-        .collect();
+    let twiddles = powers_table(omega, n / 2);
 
     if log_n <= log_threads {
         let mut chunk = 2_usize;
@@ -92,6 +90,162 @@ pub fn best_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar,
     }
 }
 
+/// Returns the twiddle factors $[1, \omega, \omega^2, \ldots, \omega^{n/2 - 1}]$
+/// for an FFT of size $n = 2^k$, when provided `log_n` = $k$. Here $\omega$ is
+/// the primitive $n$-th root of unity obtained from `F::ROOT_OF_UNITY`.
+///
+/// This function will panic if `log_n` exceeds the two-adicity `F::S`.
+pub fn twiddles<F: PrimeField>(log_n: u32) -> Vec<F> {
+    powers_table(root_of_unity(log_n), (1 << log_n) / 2)
+}
+
+/// Returns a primitive $2^k$-th root of unity, when provided `log_n` = $k$.
+fn root_of_unity<F: PrimeField>(log_n: u32) -> F {
+    assert!(log_n <= F::S);
+
+    let mut omega = F::ROOT_OF_UNITY;
+    for _ in log_n..F::S {
+        omega = omega.square();
+    }
+    omega
+}
+
+/// Performs a negacyclic Number Theoretic Transform on a vector of size
+/// $n = 2^k$, when provided `log_n` = $k$. The vector `a`, interpreted as the
+/// coefficients of a polynomial modulo $x^n + 1$, is transformed into the
+/// evaluations of this polynomial at $\psi \omega^i$, where $\psi$ is a
+/// primitive $2n$-th root of unity and $\omega = \psi^2$. Pointwise products
+/// of transformed vectors then correspond to products modulo $x^n + 1$.
+///
+/// This function will panic if the length of `a` is not `1 << log_n`, or if
+/// `log_n` + 1 exceeds the two-adicity `F::S`.
+pub fn negacyclic_ntt<F: PrimeField>(a: &mut [F], log_n: u32) {
+    assert_eq!(a.len(), 1 << log_n);
+    let psi = root_of_unity::<F>(log_n + 1);
+    let n = a.len();
+
+    for (a, psi) in a.iter_mut().zip(powers_table(psi, n)) {
+        *a *= psi;
+    }
+    best_fft(a, psi.square(), log_n);
+}
+
+/// Inverts [`negacyclic_ntt`], recovering the coefficients modulo $x^n + 1$
+/// from the evaluations at $\psi \omega^i$.
+///
+/// This function will panic if the length of `a` is not `1 << log_n`, or if
+/// `log_n` + 1 exceeds the two-adicity `F::S`.
+pub fn innegacyclic_ntt<F: PrimeField>(a: &mut [F], log_n: u32) {
+    assert_eq!(a.len(), 1 << log_n);
+    let psi_inv = root_of_unity::<F>(log_n + 1).invert().unwrap();
+    let n = a.len();
+    let n_inv = F::from(n as u64).invert().unwrap();
+
+    best_fft(a, psi_inv.square(), log_n);
+    for (a, psi_inv) in a.iter_mut().zip(powers_table(psi_inv, n)) {
+        *a *= psi_inv * n_inv;
+    }
+}
+
+/// Returns $[1, b, b^2, \ldots, b^{n - 1}]$ for `base` = $b$, using one
+/// multiplication per element.
+pub fn powers_table<F: Field>(base: F, n: usize) -> Vec<F> {
+    (0..n)
+        .scan(F::ONE, |w, _| {
+            let tw = *w;
+            *w *= &base;
+            Some(tw)
+        })
+        .collect()
+}
+
+/// Evaluates the vanishing polynomial $Z_H(x) = x^n - 1$ of the subgroup of
+/// $n = 2^k$-th roots of unity at `x`, when provided `log_n` = $k$, using $k$
+/// squarings.
+pub fn vanishing_poly_eval<F: Field>(x: &F, log_n: u32) -> F {
+    let mut x_n = *x;
+    for _ in 0..log_n {
+        x_n = x_n.square();
+    }
+    x_n - F::ONE
+}
+
+/// The multiplicative subgroup of $n = 2^k$-th roots of unity, generated by
+/// the primitive root $\omega$ obtained from `F::ROOT_OF_UNITY`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Domain<F: PrimeField> {
+    log_n: u32,
+    omega: F,
+}
+
+impl<F: PrimeField> Domain<F> {
+    /// Returns the subgroup of size $n = 2^k$, when provided `log_n` = $k$.
+    ///
+    /// This function will panic if `log_n` exceeds the two-adicity `F::S`.
+    pub fn new(log_n: u32) -> Self {
+        Domain {
+            log_n,
+            omega: root_of_unity(log_n),
+        }
+    }
+
+    /// Returns $k$ for the subgroup of size $n = 2^k$.
+    pub fn log_n(&self) -> u32 {
+        self.log_n
+    }
+
+    /// Returns the size $n$ of the subgroup.
+    pub fn size(&self) -> usize {
+        1 << self.log_n
+    }
+
+    /// Returns the generator $\omega$ of the subgroup.
+    pub fn omega(&self) -> F {
+        self.omega
+    }
+}
+
+/// Evaluates at `x` the polynomial of degree less than $n$ whose evaluations
+/// at $\omega^0, \ldots, \omega^{n - 1}$ are `evals`, without interpolating
+/// it, using the barycentric formula for roots of unity
+/// $$p(x) = \frac{x^n - 1}{n} \sum_i \frac{\omega^i e_i}{x - \omega^i}.$$
+///
+/// This runs in variable time in `x`, and will panic if `evals` does not have
+/// exactly `domain.size()` elements.
+pub fn barycentric_eval<F: PrimeField>(evals: &[F], x: &F, domain: &Domain<F>) -> F {
+    let n = domain.size();
+    assert_eq!(evals.len(), n);
+
+    let roots = powers_table(domain.omega, n);
+    let z = vanishing_poly_eval(x, domain.log_n);
+    if bool::from(z.is_zero()) {
+        // `x` is in the domain, where the formula divides by zero
+        let i = roots.iter().position(|w| w == x).unwrap();
+        return evals[i];
+    }
+
+    let mut dens: Vec<F> = roots.iter().map(|w| *x - w).collect();
+    dens.iter_mut().batch_invert();
+    let sum = evals
+        .iter()
+        .zip(roots)
+        .zip(dens)
+        .fold(F::ZERO, |acc, ((e, w), den)| acc + *e * w * den);
+    sum * z * F::from(n as u64).invert().unwrap()
+}
+
+/// Runs `a` and `b` in parallel with the `parallel` feature, one after the
+/// other without.
+fn join<A: FnOnce() + Send, B: FnOnce() + Send>(a: A, b: B) {
+    #[cfg(feature = "parallel")]
+    rayon::join(a, b);
+    #[cfg(not(feature = "parallel"))]
+    {
+        a();
+        b();
+    }
+}
+
 /// This perform recursive butterfly arithmetic
 pub fn recursive_butterfly_arithmetic<Scalar: Field, G: FftGroup<Scalar>>(
     a: &mut [G],
@@ -106,7 +260,7 @@ pub fn recursive_butterfly_arithmetic<Scalar: Field, G: FftGroup<Scalar>>(
         a[1] -= &t;
     } else {
         let (left, right) = a.split_at_mut(n / 2);
-        rayon::join(
+        join(
             || recursive_butterfly_arithmetic(left, n / 2, twiddle_chunk * 2, twiddles),
             || recursive_butterfly_arithmetic(right, n / 2, twiddle_chunk * 2, twiddles),
         );
@@ -131,3 +285,140 @@ pub fn recursive_butterfly_arithmetic<Scalar: Field, G: FftGroup<Scalar>>(
             });
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::Fr;
+
+    #[test]
+    fn test_twiddles() {
+        assert!(twiddles::<Fr>(0).is_empty());
+        assert_eq!(twiddles::<Fr>(1), vec![Fr::ONE]);
+
+        for log_n in 2..=16 {
+            let n = 1 << log_n;
+            let tw = twiddles::<Fr>(log_n);
+            assert_eq!(tw.len(), n / 2);
+            assert_eq!(tw[0], Fr::ONE);
+
+            // consecutive powers of omega
+            let omega = tw[1];
+            for (a, b) in tw.iter().zip(tw.iter().skip(1)) {
+                assert_eq!(*a * omega, *b);
+            }
+
+            // omega^(n/2) = -1, so omega has order exactly n
+            assert_eq!(tw[n / 2 - 1] * omega, -Fr::ONE);
+
+            // omega is derived from `ROOT_OF_UNITY`
+            assert_eq!(Fr::ROOT_OF_UNITY.pow([1 << (Fr::S - log_n)]), omega);
+        }
+    }
+
+    #[test]
+    fn test_negacyclic_ntt() {
+        use rand_core::OsRng;
+
+        for log_n in 0..=6 {
+            let n = 1 << log_n;
+            let a: Vec<Fr> = (0..n).map(|_| Fr::random(OsRng)).collect();
+            let b: Vec<Fr> = (0..n).map(|_| Fr::random(OsRng)).collect();
+
+            // schoolbook multiplication mod x^n + 1
+            let mut expected = vec![Fr::ZERO; n];
+            for i in 0..n {
+                for j in 0..n {
+                    if i + j < n {
+                        expected[i + j] += a[i] * b[j];
+                    } else {
+                        expected[i + j - n] -= a[i] * b[j];
+                    }
+                }
+            }
+
+            let mut a_ntt = a.clone();
+            let mut b_ntt = b.clone();
+            negacyclic_ntt(&mut a_ntt, log_n);
+            negacyclic_ntt(&mut b_ntt, log_n);
+            let mut c: Vec<Fr> = a_ntt.iter().zip(b_ntt.iter()).map(|(a, b)| a * b).collect();
+            innegacyclic_ntt(&mut c, log_n);
+            assert_eq!(c, expected);
+
+            innegacyclic_ntt(&mut a_ntt, log_n);
+            assert_eq!(a_ntt, a);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_negacyclic_ntt_length_mismatch() {
+        negacyclic_ntt(&mut [Fr::ONE; 3], 2);
+    }
+
+    #[test]
+    fn test_powers_table() {
+        use rand_core::OsRng;
+
+        let base = Fr::random(OsRng);
+        assert!(powers_table(base, 0).is_empty());
+
+        let table = powers_table(base, 1000);
+        assert_eq!(table.len(), 1000);
+        for (i, power) in table.iter().enumerate() {
+            assert_eq!(*power, base.pow([i as u64]));
+        }
+    }
+
+    #[test]
+    fn test_vanishing_poly_eval() {
+        use rand_core::OsRng;
+
+        for log_n in 0..=8 {
+            let n = 1u64 << log_n;
+            for _ in 0..10 {
+                let x = Fr::random(OsRng);
+                assert_eq!(vanishing_poly_eval(&x, log_n), x.pow([n]) - Fr::ONE);
+            }
+            assert_eq!(vanishing_poly_eval(&Fr::ZERO, log_n), -Fr::ONE);
+
+            // zero exactly on the n-th roots of unity
+            let omega = root_of_unity::<Fr>(log_n);
+            for w in powers_table(omega, n as usize) {
+                assert!(bool::from(vanishing_poly_eval(&w, log_n).is_zero()));
+            }
+            // a primitive 2n-th root of unity is not an n-th root
+            let psi = root_of_unity::<Fr>(log_n + 1);
+            assert_eq!(vanishing_poly_eval(&psi, log_n), -Fr::ONE.double());
+        }
+    }
+
+    #[test]
+    fn test_barycentric_eval() {
+        use rand_core::OsRng;
+
+        for log_n in 0..=6 {
+            let domain = Domain::<Fr>::new(log_n);
+            let n = domain.size();
+            let evals: Vec<Fr> = (0..n).map(|_| Fr::random(OsRng)).collect();
+
+            // interpolate with the inverse FFT
+            let mut coeffs = evals.clone();
+            best_fft(&mut coeffs, domain.omega().invert().unwrap(), log_n);
+            let n_inv = Fr::from(n as u64).invert().unwrap();
+            coeffs.iter_mut().for_each(|c| *c *= n_inv);
+            let horner = |x: &Fr| coeffs.iter().rev().fold(Fr::ZERO, |acc, c| acc * x + c);
+
+            for _ in 0..10 {
+                let x = Fr::random(OsRng);
+                assert_eq!(barycentric_eval(&evals, &x, &domain), horner(&x));
+            }
+
+            // on the domain itself it returns the given evaluations
+            for (w, e) in powers_table(domain.omega(), n).iter().zip(evals.iter()) {
+                assert_eq!(barycentric_eval(&evals, w, &domain), *e);
+                assert_eq!(horner(w), *e);
+            }
+        }
+    }
+}
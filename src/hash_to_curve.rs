@@ -1,8 +1,8 @@
 #![allow(clippy::op_ref)]
 
+use alloc::{boxed::Box, format, vec, vec::Vec};
 use ff::{Field, FromUniformBytes, PrimeField};
 use pasta_curves::arithmetic::CurveExt;
-use static_assertions::const_assert;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use crate::{
@@ -10,82 +10,263 @@ use crate::{
     secp256k1::{iso_map_secp256k1, IsoSecp256k1, Secp256k1},
 };
 
-/// Hashes over a message and writes the output to all of `buf`.
-/// Modified from https://github.com/zcash/pasta_curves/blob/7e3fc6a4919f6462a32b79dd226cb2587b7961eb/src/hashtocurve.rs#L11.
-fn hash_to_field<F: FromUniformBytes<64>>(
-    method: &str,
-    curve_id: &str,
-    domain_prefix: &str,
+/// BLAKE2b-512 as a [`digest::Digest`], to instantiate
+/// [`expand_message_xmd_digest`] with `blake2b_simd`.
+#[derive(Clone)]
+struct Blake2b512(blake2b_simd::State);
+
+impl Default for Blake2b512 {
+    fn default() -> Self {
+        Blake2b512(blake2b_simd::Params::new().hash_length(64).to_state())
+    }
+}
+
+impl digest::HashMarker for Blake2b512 {}
+
+impl digest::Update for Blake2b512 {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+}
+
+impl digest::OutputSizeUser for Blake2b512 {
+    type OutputSize = digest::consts::U64;
+}
+
+impl digest::core_api::BlockSizeUser for Blake2b512 {
+    type BlockSize = digest::consts::U128;
+}
+
+impl digest::FixedOutput for Blake2b512 {
+    fn finalize_into(self, out: &mut digest::Output<Self>) {
+        out.copy_from_slice(self.0.finalize().as_bytes());
+    }
+}
+
+/// Fills `out` with `expand_message_xmd` of `message` under the domain
+/// separation tag `dst`, instantiated with BLAKE2b-512.
+///
+/// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xmd>
+///
+/// This function will panic if `dst` is longer than 255 bytes or if `out` is
+/// longer than `255 * 64` bytes.
+pub fn expand_message_xmd(dst: &[u8], message: &[u8], out: &mut [u8]) {
+    expand_message_xmd_digest::<Blake2b512>(dst, message, out)
+}
+
+/// Fills `out` with `expand_message_xof` of `message` under the domain
+/// separation tag `dst`, instantiated with SHAKE256.
+///
+/// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xof>
+///
+/// This function will panic if `dst` is longer than 255 bytes or if `out` is
+/// longer than `u16::MAX` bytes.
+#[cfg(feature = "sha3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sha3")))]
+pub fn expand_message_xof(dst: &[u8], message: &[u8], out: &mut [u8]) {
+    use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+    assert!(dst.len() < 256);
+    let len = u16::try_from(out.len()).unwrap();
+
+    let mut hasher = sha3::Shake256::default();
+    hasher.update(message);
+    hasher.update(&len.to_be_bytes());
+    hasher.update(dst);
+    hasher.update(&[dst.len() as u8]);
+    hasher.finalize_xof().read(out);
+}
+
+/// Fills `out` with `expand_message_xmd` of `message` under the domain
+/// separation tag `dst`, instantiated with the Merkle-Damgård hash `D`.
+///
+/// `b_in_bytes` and `r_in_bytes` of RFC 9380 are the output and block sizes
+/// of `D`. See [`expand_message_xmd_sha256`] and
+/// [`expand_message_xmd_keccak256`] for ready made instantiations.
+///
+/// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xmd>
+///
+/// This function will panic if `dst` is longer than 255 bytes or if `out` is
+/// longer than `255` output blocks of `D`.
+pub fn expand_message_xmd_digest<D>(dst: &[u8], message: &[u8], out: &mut [u8])
+where
+    D: digest::Digest + digest::core_api::BlockSizeUser,
+{
+    let b_in_bytes = <D as digest::Digest>::output_size();
+    let r_in_bytes = D::block_size();
+    assert!(dst.len() < 256);
+    let ell = (out.len() + b_in_bytes - 1) / b_in_bytes;
+    assert!(ell < 256);
+
+    let dst_prime = |hasher: D| hasher.chain_update(dst).chain_update([dst.len() as u8]);
+
+    let b_0 = dst_prime(
+        D::new()
+            .chain_update(vec![0u8; r_in_bytes])
+            .chain_update(message)
+            .chain_update((out.len() as u16).to_be_bytes())
+            .chain_update([0]),
+    )
+    .finalize();
+
+    let mut b_i = vec![0u8; b_in_bytes];
+    for (i, chunk) in out.chunks_mut(b_in_bytes).enumerate() {
+        for (b_0, b_i) in b_0.iter().zip(b_i.iter_mut()) {
+            *b_i ^= b_0;
+        }
+        let hasher = D::new().chain_update(&b_i).chain_update([i as u8 + 1]);
+        b_i.copy_from_slice(&dst_prime(hasher).finalize());
+        chunk.copy_from_slice(&b_i[..chunk.len()]);
+    }
+}
+
+/// Fills `out` with `expand_message_xmd` of `message` under the domain
+/// separation tag `dst`, instantiated with SHA-256.
+///
+/// This function will panic if `dst` is longer than 255 bytes or if `out` is
+/// longer than `255 * 32` bytes.
+#[cfg(feature = "sha2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sha2")))]
+pub fn expand_message_xmd_sha256(dst: &[u8], message: &[u8], out: &mut [u8]) {
+    expand_message_xmd_digest::<sha2::Sha256>(dst, message, out)
+}
+
+/// Fills `out` with `expand_message_xmd` of `message` under the domain
+/// separation tag `dst`, instantiated with Keccak-256, the pre-standard
+/// padding of SHA3-256 used by Ethereum.
+///
+/// This function will panic if `dst` is longer than 255 bytes or if `out` is
+/// longer than `255 * 32` bytes.
+#[cfg(feature = "sha3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sha3")))]
+pub fn expand_message_xmd_keccak256(dst: &[u8], message: &[u8], out: &mut [u8]) {
+    expand_message_xmd_digest::<sha3::Keccak256>(dst, message, out)
+}
+
+/// Hashes `message` to `N` field elements under the domain separation tag
+/// `dst`, using [`expand_message_xmd`] and reducing 64 bytes per element.
+///
+/// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-hash_to_field-implementatio>
+pub fn hash_to_field<F: FromUniformBytes<64>, const N: usize>(
+    dst: &[u8],
+    message: &[u8],
+) -> [F; N] {
+    hash_to_field_with(expand_message_xmd, dst, message)
+}
+
+/// Like [`hash_to_field`], but with the message expansion given by `expand`,
+/// e.g. `expand_message_xof`, called as `expand(dst, message, out)`.
+pub fn hash_to_field_with<F: FromUniformBytes<64>, const N: usize>(
+    expand: impl Fn(&[u8], &[u8], &mut [u8]),
+    dst: &[u8],
     message: &[u8],
-    buf: &mut [F; 2],
-) {
+) -> [F; N] {
+    hash_to_field_vec::<F, 64>(expand, dst, message, N)
+        .try_into()
+        .unwrap()
+}
+
+/// Hashes `message` to `count` field elements under the domain separation
+/// tag `dst`, expanding it with `expand` and reducing `L` bytes per element.
+///
+/// With an RFC 9380 expander, e.g. [`expand_message_xmd_sha256`], and
+/// `L = ceil((ceil(log2(p)) + k) / 8)` for the security level `k`, this is
+/// the `hash_to_field` of the RFC. For 254 bit fields at `k = 128` that is
+/// `L = 48`. Unlike [`hash_to_field`], the number of elements is chosen at
+/// runtime, which suits Fiat-Shamir transcripts hashing straight to scalars.
+///
+/// The tag comes before the message, as in the expanders and
+/// [`hash_to_field`] of this module.
+///
+/// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-hash_to_field-implementatio>
+pub fn hash_to_field_vec<F: FromUniformBytes<L>, const L: usize>(
+    expand: impl Fn(&[u8], &[u8], &mut [u8]),
+    dst: &[u8],
+    message: &[u8],
+    count: usize,
+) -> Vec<F> {
+    let mut uniform_bytes = vec![0u8; count * L];
+    expand(dst, message, &mut uniform_bytes);
+
+    uniform_bytes
+        .chunks(L)
+        .map(|chunk| {
+            let mut little = [0u8; L];
+            little.copy_from_slice(chunk);
+            little.reverse();
+            F::from_uniform_bytes(&little)
+        })
+        .collect()
+}
+
+/// Hashes `message` to `N` field elements under the domain separation tag
+/// `dst` as the `*_XMD:SHA-256_*` suites of RFC 9380 do for fields of at most
+/// 256 bits, i.e. with [`expand_message_xmd_sha256`] and `L = 48`.
+#[cfg(feature = "sha2")]
+pub(crate) fn hash_to_field_sha256<F: FromUniformBytes<64>, const N: usize>(
+    dst: &[u8],
+    message: &[u8],
+) -> [F; N] {
+    const L: usize = 48;
+    let mut uniform_bytes = vec![0u8; N * L];
+    expand_message_xmd_sha256(dst, message, &mut uniform_bytes);
+
+    let mut chunks = uniform_bytes.chunks(L);
+    [(); N].map(|_| {
+        // zero extending the big endian chunk does not change its reduction
+        let mut little = [0u8; 64];
+        little[..L].copy_from_slice(chunks.next().unwrap());
+        little[..L].reverse();
+        F::from_uniform_bytes(&little)
+    })
+}
+
+/// `hash_to_curve` of RFC 9380 for the `*_XMD:SHA-256_*_RO_` suites of
+/// curves with cofactor one, with the given `map_to_curve`.
+///
+/// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-encoding-byte-strings-to-el>
+#[cfg(feature = "sha2")]
+pub(crate) fn hash_to_curve_sha256<C>(
+    dst: &[u8],
+    message: &[u8],
+    map_to_curve: impl Fn(C::Base) -> C,
+) -> C
+where
+    C: CurveExt,
+    C::Base: FromUniformBytes<64>,
+{
+    let [u0, u1] = hash_to_field_sha256(dst, message);
+
+    let r = map_to_curve(u0) + map_to_curve(u1);
+    debug_assert!(bool::from(r.is_on_curve()));
+    r
+}
+
+/// `encode_to_curve` of RFC 9380 for the `*_XMD:SHA-256_*_NU_` suites of
+/// curves with cofactor one, with the given `map_to_curve`.
+///
+/// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-encoding-byte-strings-to-el>
+#[cfg(feature = "sha2")]
+pub(crate) fn encode_to_curve_sha256<C>(
+    dst: &[u8],
+    message: &[u8],
+    map_to_curve: impl Fn(C::Base) -> C,
+) -> C
+where
+    C: CurveExt,
+    C::Base: FromUniformBytes<64>,
+{
+    let [u] = hash_to_field_sha256(dst, message);
+    map_to_curve(u)
+}
+
+/// Domain separation tag used by the hash to curve functions of this crate.
+/// Modified from https://github.com/zcash/pasta_curves/blob/7e3fc6a4919f6462a32b79dd226cb2587b7961eb/src/hashtocurve.rs#L11.
+fn curve_dst(method: &str, curve_id: &str, domain_prefix: &str) -> Vec<u8> {
     assert!(domain_prefix.len() < 256);
     assert!((18 + method.len() + curve_id.len() + domain_prefix.len()) < 256);
 
-    // Assume that the field size is 32 bytes and k is 256, where k is defined in
-    // <https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-10.html#name-security-considerations-3>.
-    const CHUNKLEN: usize = 64;
-    const_assert!(CHUNKLEN * 2 < 256);
-
-    // Input block size of BLAKE2b.
-    const R_IN_BYTES: usize = 128;
-
-    let personal = [0u8; 16];
-    let empty_hasher = blake2b_simd::Params::new()
-        .hash_length(CHUNKLEN)
-        .personal(&personal)
-        .to_state();
-
-    let b_0 = empty_hasher
-        .clone()
-        .update(&[0; R_IN_BYTES])
-        .update(message)
-        .update(&[0, (CHUNKLEN * 2) as u8, 0])
-        .update(domain_prefix.as_bytes())
-        .update(b"-")
-        .update(curve_id.as_bytes())
-        .update(b"_XMD:BLAKE2b_")
-        .update(method.as_bytes())
-        .update(b"_RO_")
-        .update(&[(18 + method.len() + curve_id.len() + domain_prefix.len()) as u8])
-        .finalize();
-
-    let b_1 = empty_hasher
-        .clone()
-        .update(b_0.as_array())
-        .update(&[1])
-        .update(domain_prefix.as_bytes())
-        .update(b"-")
-        .update(curve_id.as_bytes())
-        .update(b"_XMD:BLAKE2b_")
-        .update(method.as_bytes())
-        .update(b"_RO_")
-        .update(&[(18 + method.len() + curve_id.len() + domain_prefix.len()) as u8])
-        .finalize();
-
-    let b_2 = {
-        let mut empty_hasher = empty_hasher;
-        for (l, r) in b_0.as_array().iter().zip(b_1.as_array().iter()) {
-            empty_hasher.update(&[*l ^ *r]);
-        }
-        empty_hasher
-            .update(&[2])
-            .update(domain_prefix.as_bytes())
-            .update(b"-")
-            .update(curve_id.as_bytes())
-            .update(b"_XMD:BLAKE2b_")
-            .update(method.as_bytes())
-            .update(b"_RO_")
-            .update(&[(18 + method.len() + curve_id.len() + domain_prefix.len()) as u8])
-            .finalize()
-    };
-
-    for (big, buf) in [b_1, b_2].iter().zip(buf.iter_mut()) {
-        let mut little = [0u8; CHUNKLEN];
-        little.copy_from_slice(big.as_array());
-        little.reverse();
-        *buf = F::from_uniform_bytes(&little);
-    }
+    format!("{domain_prefix}-{curve_id}_XMD:BLAKE2b_{method}_RO_").into_bytes()
 }
 
 // Implementation of <https://datatracker.ietf.org/doc/html/rfc9380#name-simplified-swu-method>
@@ -165,9 +346,10 @@ where
     C: CurveExt,
     C::Base: FromUniformBytes<64>,
 {
+    let dst = curve_dst("SSWU", curve_id, domain_prefix);
+
     Box::new(move |message| {
-        let mut us = [C::Base::ZERO; 2];
-        hash_to_field("SSWU", curve_id, domain_prefix, message, &mut us);
+        let us: [C::Base; 2] = hash_to_field(&dst, message);
 
         let [q0, q1]: [C; 2] = us.map(|u| sswu_map_to_curve::<C>(u, z));
 
@@ -334,9 +516,10 @@ where
 {
     let [c1, c2, c3, c4] = svdw_precomputed_constants::<C>(z);
 
+    let dst = curve_dst("SVDW", curve_id, domain_prefix);
+
     Box::new(move |message| {
-        let mut us = [C::Base::ZERO; 2];
-        hash_to_field("SVDW", curve_id, domain_prefix, message, &mut us);
+        let us: [C::Base; 2] = hash_to_field(&dst, message);
 
         let [q0, q1]: [C; 2] = us.map(|u| svdw_map_to_curve(u, c1, c2, c3, c4, z));
 
@@ -368,3 +551,245 @@ pub(crate) fn svdw_precomputed_constants<C: CurveExt>(z: C::Base) -> [C::Base; 4
 
     [c1, c2, c3, c4]
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::Fq;
+
+    #[test]
+    fn test_expand_message_xmd() {
+        let dst = b"QUUX-V01-CS02-with-expander-BLAKE2b";
+        let personal = [0u8; 16];
+        let hasher = blake2b_simd::Params::new()
+            .hash_length(64)
+            .personal(&personal)
+            .to_state();
+
+        for message in [&b""[..], b"abc", &[0x61; 1000]] {
+            for len in [1, 32, 64, 65, 128, 200, 254 * 64] {
+                let mut out = vec![0u8; len];
+                expand_message_xmd(dst, message, &mut out);
+
+                // first block, b_1 = H(b_0 || 1 || dst_prime)
+                let b_0 = hasher
+                    .clone()
+                    .update(&[0; 128])
+                    .update(message)
+                    .update(&(len as u16).to_be_bytes())
+                    .update(&[0])
+                    .update(dst)
+                    .update(&[dst.len() as u8])
+                    .finalize();
+                let b_1 = hasher
+                    .clone()
+                    .update(b_0.as_bytes())
+                    .update(&[1])
+                    .update(dst)
+                    .update(&[dst.len() as u8])
+                    .finalize();
+                let n = len.min(64);
+                assert_eq!(out[..n], b_1.as_bytes()[..n]);
+
+                // the output length is bound into every block
+                let mut longer = vec![0u8; len + 1];
+                expand_message_xmd(dst, message, &mut longer);
+                assert_ne!(out[..n], longer[..n]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hash_to_field() {
+        let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:BLAKE2b_SVDW_RO_";
+        for message in [&b""[..], b"abc", b"abcdef0123456789"] {
+            let mut uniform_bytes = [0u8; 128];
+            expand_message_xmd(dst, message, &mut uniform_bytes);
+
+            let us: [Fq; 2] = hash_to_field(dst, message);
+            for (u, chunk) in us.iter().zip(uniform_bytes.chunks(64)) {
+                let mut little: [u8; 64] = chunk.try_into().unwrap();
+                little.reverse();
+                assert_eq!(*u, Fq::from_uniform_bytes(&little));
+            }
+
+            // a single element expands a different amount of bytes
+            let [u]: [Fq; 1] = hash_to_field(dst, message);
+            assert_ne!(u, us[0]);
+            let [] = hash_to_field::<Fq, 0>(dst, message);
+        }
+    }
+
+    #[cfg(feature = "sha3")]
+    #[test]
+    fn test_expand_message_xof() {
+        // Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xofshake256>
+        let dst = b"QUUX-V01-CS02-with-expander-SHAKE256";
+        let vectors: [(&[u8], usize, &str); 3] = [
+            (
+                b"",
+                0x20,
+                "2ffc05c48ed32b95d72e807f6eab9f7530dd1c2f013914c8fed38c5ccc15ad76",
+            ),
+            (
+                b"abc",
+                0x20,
+                "b39e493867e2767216792abce1f2676c197c0692aed061560ead251821808e07",
+            ),
+            (
+                b"abcdef0123456789",
+                0x20,
+                "245389cf44a13f0e70af8665fe5337ec2dcd138890bb7901c4ad9cfceb054b65",
+            ),
+        ];
+        for (message, len, expected) in vectors {
+            let mut out = vec![0u8; len];
+            expand_message_xof(dst, message, &mut out);
+            assert_eq!(hex::encode(out), expected);
+        }
+
+        let us: [Fq; 2] = hash_to_field_with(expand_message_xof, dst, b"abc");
+        let mut uniform_bytes = [0u8; 128];
+        expand_message_xof(dst, b"abc", &mut uniform_bytes);
+        for (u, chunk) in us.iter().zip(uniform_bytes.chunks(64)) {
+            let mut little: [u8; 64] = chunk.try_into().unwrap();
+            little.reverse();
+            assert_eq!(*u, Fq::from_uniform_bytes(&little));
+        }
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn test_expand_message_xmd_sha256() {
+        use crate::bn256::Fr;
+
+        // Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xmdsha-256>
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let vectors: [(&[u8], usize, &str); 4] = [
+            (
+                b"",
+                0x20,
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                0x20,
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"abcdef0123456789",
+                0x20,
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+            (
+                b"abc",
+                0x80,
+                "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a\
+                 647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635\
+                 bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00\
+                 058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+            ),
+        ];
+        for (message, len, expected) in vectors {
+            let mut out = vec![0u8; len];
+            expand_message_xmd_sha256(dst, message, &mut out);
+            assert_eq!(hex::encode(out), expected);
+        }
+
+        // hash_to_field with L = 48, as for BN254 at the 128 bit level
+        let dst = b"QUUX-V01-CS02-with-BN254FR_XMD:SHA-256_";
+        let expected = [
+            "0x25b320a2234aa8e0aa1fe59d5481cdad5d969202906faf073939954cd6339060",
+            "0x06efd2e3cad2b2e86d58212db032dbddc27e6b7e32c5e6b816508facb0b5689f",
+            "0x246337416ea1e10cd60abd4a6c06c95a6ee7e423acb0763c78d4c8f0fcbf9cd7",
+        ];
+        for count in 0..=expected.len() {
+            let us = hash_to_field_vec::<Fr, 48>(expand_message_xmd_sha256, dst, b"abc", count);
+            assert_eq!(us.len(), count);
+            // the expanded length, and so every element, depends on `count`
+            if count == expected.len() {
+                for (u, expected) in us.iter().zip(expected) {
+                    assert_eq!(format!("{:?}", u), expected);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "sha3")]
+    #[test]
+    fn test_expand_message_xmd_keccak256() {
+        use crate::bn256::Fr;
+        use sha3::{Digest, Keccak256};
+
+        // Ethereum's Keccak-256, which differs from SHA3-256 only in its
+        // padding. The empty digest is the empty code hash of the Ethereum
+        // yellow paper.
+        assert_eq!(
+            hex::encode(Keccak256::digest(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(Keccak256::digest(b"abc")),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+
+        // No RFC 9380 vectors exist for Keccak-256. The expansion itself is
+        // the one checked against the SHA-256 vectors above, so check the
+        // blocks against Keccak-256 directly.
+        let dst = b"QUUX-V01-CS02-with-expander-KECCAK256";
+        let dst_prime = [&dst[..], &[dst.len() as u8]].concat();
+        for message in [&b""[..], b"abc", b"abcdef0123456789"] {
+            let mut out = [0u8; 0x40];
+            expand_message_xmd_keccak256(dst, message, &mut out);
+
+            let b_0 = Keccak256::new()
+                .chain_update([0; 136])
+                .chain_update(message)
+                .chain_update(0x40u16.to_be_bytes())
+                .chain_update([0])
+                .chain_update(&dst_prime)
+                .finalize();
+            let b_1 = Keccak256::new()
+                .chain_update(b_0)
+                .chain_update([1])
+                .chain_update(&dst_prime)
+                .finalize();
+            let b_0_xor_b_1: Vec<u8> = b_0.iter().zip(b_1.iter()).map(|(a, b)| a ^ b).collect();
+            let b_2 = Keccak256::new()
+                .chain_update(b_0_xor_b_1)
+                .chain_update([2])
+                .chain_update(&dst_prime)
+                .finalize();
+            assert_eq!(out, [b_1, b_2].concat()[..]);
+        }
+
+        let dst = b"QUUX-V01-CS02-with-BN254FR_XMD:KECCAK-256_";
+        let us = hash_to_field_vec::<Fr, 48>(expand_message_xmd_keccak256, dst, b"abc", 3);
+        assert_eq!(us.len(), 3);
+        let mut uniform_bytes = [0u8; 3 * 48];
+        expand_message_xmd_keccak256(dst, b"abc", &mut uniform_bytes);
+        for (u, chunk) in us.iter().zip(uniform_bytes.chunks(48)) {
+            let mut little: [u8; 48] = chunk.try_into().unwrap();
+            little.reverse();
+            assert_eq!(
+                *u,
+                <Fr as FromUniformBytes<48>>::from_uniform_bytes(&little)
+            );
+        }
+    }
+
+    #[test]
+    fn test_hash_to_field_vec() {
+        let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:BLAKE2b_SVDW_RO_";
+        for count in [0, 1, 2, 5] {
+            let us: Vec<Fq> = hash_to_field_vec(expand_message_xmd, dst, b"abc", count);
+            assert_eq!(us.len(), count);
+        }
+        // agrees with the fixed size variant
+        let us: [Fq; 2] = hash_to_field(dst, b"abc");
+        assert_eq!(
+            hash_to_field_vec::<Fq, 64>(expand_message_xmd, dst, b"abc", 2),
+            us
+        );
+    }
+}
@@ -1,3 +1,7 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 mod arithmetic;
 pub mod ff_ext;
 pub mod fft;
@@ -17,6 +21,7 @@ pub mod secq256k1;
 mod derive;
 
 // Re-export to simplify down stream dependencies
+pub use arithmetic::CurveCofactor;
 pub use ff;
 pub use group;
 pub use pairing;
@@ -1,10 +1,13 @@
-use std::ops::Neg;
+use alloc::{vec, vec::Vec};
+use core::ops::Neg;
 
 use ff::PrimeField;
-use group::Group;
-use pasta_curves::arithmetic::CurveAffine;
+use group::{Curve, Group};
+use pasta_curves::arithmetic::{CurveAffine, CurveExt};
 
-fn get_booth_index(window_index: usize, window_size: usize, el: &[u8]) -> i32 {
+use crate::arithmetic::CurveEndo;
+
+pub(crate) fn get_booth_index(window_index: usize, window_size: usize, el: &[u8]) -> i32 {
     // Booth encoding:
     // * step by `window` size
     // * slice by size of `window + 1``
@@ -48,77 +51,203 @@ fn get_booth_index(window_index: usize, window_size: usize, el: &[u8]) -> i32 {
     }
 }
 
-pub fn multiexp_serial<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C], acc: &mut C::Curve) {
-    let coeffs: Vec<_> = coeffs.iter().map(|a| a.to_repr()).collect();
+/// Largest window accepted by `msm_with_window`: a Booth digit of
+/// `window_bits + 1` bits has to fit in the `u32` read by `get_booth_index`
+/// at any bit offset within a byte.
+pub const MAX_WINDOW: usize = 24;
 
-    let c = if bases.len() < 4 {
+/// `floor(e^k)` for `k = 0, 1, ..., 22`, the last one below `2^32`.
+const EXP_FLOORS: [u32; 23] = [
+    1, 2, 7, 20, 54, 148, 403, 1096, 2980, 8103, 22026, 59874, 162754, 442413, 1202604, 3269017,
+    8886110, 24154952, 65659969, 178482300, 485165195, 1318815734, 3584912846,
+];
+
+/// Returns the bucket window size the bucket method picks for `n` points.
+pub fn best_window(n: usize) -> usize {
+    if n < 4 {
         1
-    } else if bases.len() < 32 {
+    } else if n < 32 {
         3
     } else {
-        (f64::from(bases.len() as u32)).ln().ceil() as usize
-    };
+        // `ceil(ln(n))` without floating point, which `core` lacks: as `e^k`
+        // is irrational for `k > 0`, `n <= e^k` if and only if `n <= floor(e^k)`
+        let n = n as u32;
+        EXP_FLOORS.iter().take_while(|&&e| e < n).count()
+    }
+}
 
-    let number_of_windows = C::Scalar::NUM_BITS as usize / c + 1;
+/// Splits `coeffs` and `bases` into one chunk per rayon thread, runs
+/// `serial` on every chunk in parallel and sums the partial results. The
+/// chunks are summed in order, so the result does not depend on scheduling.
+///
+/// Without the `parallel` feature, or for inputs not larger than the number
+/// of threads, `serial` runs once over the whole input.
+fn multiexp_chunks<C: CurveAffine, T: Sync>(
+    coeffs: &[T],
+    bases: &[C],
+    serial: impl Fn(&[T], &[C], &mut C::Curve) + Sync,
+) -> C::Curve {
+    #[cfg(feature = "parallel")]
+    {
+        let num_threads = rayon::current_num_threads();
+        if num_threads > 1 && coeffs.len() > num_threads {
+            let chunk = coeffs.len() / num_threads;
+            let num_chunks = coeffs.chunks(chunk).len();
+            let mut results = vec![C::Curve::identity(); num_chunks];
+            rayon::scope(|scope| {
+                let serial = &serial;
+                for ((coeffs, bases), acc) in coeffs
+                    .chunks(chunk)
+                    .zip(bases.chunks(chunk))
+                    .zip(results.iter_mut())
+                {
+                    scope.spawn(move |_| serial(coeffs, bases, acc));
+                }
+            });
+            return results.iter().fold(C::Curve::identity(), |a, b| a + b);
+        }
+    }
+
+    let mut acc = C::Curve::identity();
+    serial(coeffs, bases, &mut acc);
+    acc
+}
+
+pub fn multiexp_serial<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C], acc: &mut C::Curve) {
+    let coeffs: Vec<_> = coeffs.iter().map(|a| a.to_repr()).collect();
+    multiexp_serial_bits(
+        &coeffs,
+        bases,
+        C::Scalar::NUM_BITS as usize,
+        best_window(bases.len()),
+        acc,
+    );
+}
+
+/// Bucket method with windows of `c` bits over little-endian scalar
+/// encodings of at most `num_bits` bits.
+fn multiexp_serial_bits<C: CurveAffine, R: AsRef<[u8]>>(
+    coeffs: &[R],
+    bases: &[C],
+    num_bits: usize,
+    c: usize,
+    acc: &mut C::Curve,
+) {
+    let number_of_windows = num_bits / c + 1;
 
     for current_window in (0..number_of_windows).rev() {
         for _ in 0..c {
             *acc = acc.double();
         }
+        *acc += window_sum(coeffs, bases, current_window, c);
+    }
+}
 
-        #[derive(Clone, Copy)]
-        enum Bucket<C: CurveAffine> {
-            None,
-            Affine(C),
-            Projective(C::Curve),
-        }
+/// Returns the sum of `bases` weighted by the Booth digits of `coeffs` in
+/// window `current_window` of `c` bits.
+fn window_sum<C: CurveAffine, R: AsRef<[u8]>>(
+    coeffs: &[R],
+    bases: &[C],
+    current_window: usize,
+    c: usize,
+) -> C::Curve {
+    #[derive(Clone, Copy)]
+    enum Bucket<C: CurveAffine> {
+        None,
+        Affine(C),
+        Projective(C::Curve),
+    }
 
-        impl<C: CurveAffine> Bucket<C> {
-            fn add_assign(&mut self, other: &C) {
-                *self = match *self {
-                    Bucket::None => Bucket::Affine(*other),
-                    Bucket::Affine(a) => Bucket::Projective(a + *other),
-                    Bucket::Projective(mut a) => {
-                        a += *other;
-                        Bucket::Projective(a)
-                    }
+    impl<C: CurveAffine> Bucket<C> {
+        fn add_assign(&mut self, other: &C) {
+            *self = match *self {
+                Bucket::None => Bucket::Affine(*other),
+                Bucket::Affine(a) => Bucket::Projective(a + *other),
+                Bucket::Projective(mut a) => {
+                    a += *other;
+                    Bucket::Projective(a)
                 }
             }
+        }
 
-            fn add(self, mut other: C::Curve) -> C::Curve {
-                match self {
-                    Bucket::None => other,
-                    Bucket::Affine(a) => {
-                        other += a;
-                        other
-                    }
-                    Bucket::Projective(a) => other + a,
+        fn add(self, mut other: C::Curve) -> C::Curve {
+            match self {
+                Bucket::None => other,
+                Bucket::Affine(a) => {
+                    other += a;
+                    other
                 }
+                Bucket::Projective(a) => other + a,
             }
         }
+    }
 
-        let mut buckets: Vec<Bucket<C>> = vec![Bucket::None; 1 << (c - 1)];
+    let mut buckets: Vec<Bucket<C>> = vec![Bucket::None; 1 << (c - 1)];
 
-        for (coeff, base) in coeffs.iter().zip(bases.iter()) {
-            let coeff = get_booth_index(current_window, c, coeff.as_ref());
-            if coeff.is_positive() {
-                buckets[coeff as usize - 1].add_assign(base);
-            }
-            if coeff.is_negative() {
-                buckets[coeff.unsigned_abs() as usize - 1].add_assign(&base.neg());
-            }
+    for (coeff, base) in coeffs.iter().zip(bases.iter()) {
+        let coeff = get_booth_index(current_window, c, coeff.as_ref());
+        if coeff.is_positive() {
+            buckets[coeff as usize - 1].add_assign(base);
         }
-
-        // Summation by parts
-        // e.g. 3a + 2b + 1c = a +
-        //                    (a) + b +
-        //                    ((a) + b) + c
-        let mut running_sum = C::Curve::identity();
-        for exp in buckets.into_iter().rev() {
-            running_sum = exp.add(running_sum);
-            *acc += &running_sum;
+        if coeff.is_negative() {
+            // negating an affine point only negates `y`, no group operation
+            buckets[coeff.unsigned_abs() as usize - 1].add_assign(&base.neg());
         }
     }
+
+    // Summation by parts
+    // e.g. 3a + 2b + 1c = a +
+    //                    (a) + b +
+    //                    ((a) + b) + c
+    // The two accumulators only ever add, and negative digits were folded
+    // into the buckets above by negating affine bases, so the reduction
+    // needs no negation. Empty buckets above the highest filled one would
+    // only add the identity to both accumulators, so they are skipped.
+    let mut running_sum = C::Curve::identity();
+    let mut sum = C::Curve::identity();
+    for exp in buckets
+        .into_iter()
+        .rev()
+        .skip_while(|b| matches!(b, Bucket::None))
+    {
+        running_sum = exp.add(running_sum);
+        sum += &running_sum;
+    }
+    sum
+}
+
+/// Performs a multi-exponentiation operation with windows of `c` bits and
+/// also returns the partial sum of every window, least significant first.
+///
+/// The result is $\sum_i 2^{c i} W_i$ for the window partial sums $W_i$, so
+/// folding them from the most significant one by Horner's rule with factor
+/// $2^c$ reproduces it.
+///
+/// This function will panic if scalars and bases have a different length,
+/// or if `c` is not in `1..=MAX_WINDOW`.
+#[cfg(feature = "debug-msm")]
+pub fn multiexp_debug<C: CurveAffine>(
+    scalars: &[C::Scalar],
+    bases: &[C],
+    c: usize,
+) -> (C::Curve, Vec<C::Curve>) {
+    assert_eq!(scalars.len(), bases.len());
+    assert!((1..=MAX_WINDOW).contains(&c));
+
+    let coeffs: Vec<_> = scalars.iter().map(|a| a.to_repr()).collect();
+    let number_of_windows = C::Scalar::NUM_BITS as usize / c + 1;
+    let windows: Vec<_> = (0..number_of_windows)
+        .map(|window| window_sum(&coeffs, bases, window, c))
+        .collect();
+
+    // the same double-and-add over the windows as `multiexp_serial_bits`
+    let acc = windows
+        .iter()
+        .rev()
+        .fold(C::Curve::identity(), |acc, window| {
+            (0..c).fold(acc, |acc, _| acc.double()) + window
+        });
+    (acc, windows)
 }
 
 /// Performs a small multi-exponentiation operation.
@@ -149,47 +278,130 @@ pub fn small_multiexp<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::C
 ///
 /// This function will panic if coeffs and bases have a different length.
 ///
-/// This will use multithreading if beneficial.
+/// This will use multithreading if beneficial and the `parallel` feature is
+/// enabled.
 pub fn best_multiexp<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
     assert_eq!(coeffs.len(), bases.len());
 
-    let num_threads = rayon::current_num_threads();
-    if coeffs.len() > num_threads {
-        let chunk = coeffs.len() / num_threads;
-        let num_chunks = coeffs.chunks(chunk).len();
-        let mut results = vec![C::Curve::identity(); num_chunks];
-        rayon::scope(|scope| {
-            let chunk = coeffs.len() / num_threads;
+    multiexp_chunks(coeffs, bases, multiexp_serial)
+}
 
-            for ((coeffs, bases), acc) in coeffs
-                .chunks(chunk)
-                .zip(bases.chunks(chunk))
-                .zip(results.iter_mut())
-            {
-                scope.spawn(move |_| {
-                    multiexp_serial(coeffs, bases, acc);
-                });
-            }
-        });
-        results.iter().fold(C::Curve::identity(), |a, b| a + b)
-    } else {
-        let mut acc = C::Curve::identity();
-        multiexp_serial(coeffs, bases, &mut acc);
-        acc
+/// Performs a multi-exponentiation operation over projective bases, such as
+/// the output of a previous computation, normalizing them all at once with a
+/// single batch inversion before running [`best_multiexp`].
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial and the `parallel` feature is
+/// enabled.
+pub fn best_multiexp_projective<C: CurveExt>(coeffs: &[C::ScalarExt], bases: &[C]) -> C {
+    assert_eq!(coeffs.len(), bases.len());
+
+    let mut affine_bases = vec![C::identity().to_affine(); bases.len()];
+    C::batch_normalize(bases, &mut affine_bases);
+    best_multiexp(coeffs, &affine_bases)
+}
+
+/// Performs a multi-exponentiation operation with a fixed bucket window of
+/// `window_bits` bits instead of the one picked by [`best_window`].
+///
+/// Returns the identity for empty input.
+///
+/// This function will panic if coeffs and bases have a different length, or
+/// if `window_bits` is not in `1..=MAX_WINDOW`.
+///
+/// This will use multithreading if beneficial and the `parallel` feature is
+/// enabled.
+pub fn msm_with_window<C: CurveAffine>(
+    coeffs: &[C::Scalar],
+    bases: &[C],
+    window_bits: usize,
+) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+    assert!((1..=MAX_WINDOW).contains(&window_bits));
+
+    let coeffs: Vec<_> = coeffs.iter().map(|a| a.to_repr()).collect();
+    let num_bits = C::Scalar::NUM_BITS as usize;
+
+    multiexp_chunks(&coeffs, bases, |coeffs, bases, acc| {
+        multiexp_serial_bits(coeffs, bases, num_bits, window_bits, acc)
+    })
+}
+
+/// Performs a multi-exponentiation operation using the GLV endomorphism.
+///
+/// Every scalar `k` is decomposed as `k = k1 + ZETA * k2` with `k1, k2`
+/// of roughly half the bit length, so the bucket method runs over twice as
+/// many points `(B_i, endo(B_i))` but only half as many windows.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial and the `parallel` feature is
+/// enabled.
+pub fn best_multiexp_glv<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve
+where
+    C::CurveExt: CurveEndo,
+{
+    assert_eq!(coeffs.len(), bases.len());
+
+    let endo_bases: Vec<C::Curve> = bases.iter().map(|base| base.to_curve().endo()).collect();
+    let mut endo_affine = vec![C::identity(); bases.len()];
+    C::Curve::batch_normalize(&endo_bases, &mut endo_affine);
+
+    // `k = ±k1 ∓ ZETA * k2`, see `CurveEndo::decompose_scalar`
+    let mut glv_coeffs = Vec::with_capacity(2 * coeffs.len());
+    let mut glv_bases = Vec::with_capacity(2 * bases.len());
+    for ((coeff, base), endo_base) in coeffs.iter().zip(bases.iter()).zip(endo_affine.iter()) {
+        let (k1, k1_neg, k2, k2_neg) = C::CurveExt::decompose_scalar(coeff);
+        glv_coeffs.push(k1.to_le_bytes());
+        glv_bases.push(if k1_neg { base.neg() } else { *base });
+        glv_coeffs.push(k2.to_le_bytes());
+        glv_bases.push(if k2_neg { *endo_base } else { endo_base.neg() });
     }
+
+    multiexp_chunks(&glv_coeffs, &glv_bases, |coeffs, bases, acc| {
+        multiexp_serial_bits(coeffs, bases, 128, best_window(bases.len()), acc)
+    })
+}
+
+/// Computes $\sum_i c_i \sum_j s_{ij} B_{ij}$ for the `(s_ij, B_ij)` pairs of
+/// each of `proofs` and the corresponding `challenges` $c_i$, with a single
+/// multi-exponentiation over all pairs.
+///
+/// This function will panic if proofs and challenges have a different length.
+///
+/// This will use multithreading if beneficial and the `parallel` feature is
+/// enabled.
+pub fn random_linear_combination<C: CurveAffine>(
+    proofs: &[&[(C::Scalar, C)]],
+    challenges: &[C::Scalar],
+) -> C::Curve {
+    assert_eq!(proofs.len(), challenges.len());
+
+    let (coeffs, bases): (Vec<_>, Vec<_>) = proofs
+        .iter()
+        .zip(challenges.iter())
+        .flat_map(|(proof, challenge)| {
+            proof
+                .iter()
+                .map(move |(scalar, base)| (*scalar * challenge, *base))
+        })
+        .unzip();
+
+    best_multiexp(&coeffs, &bases)
 }
 
 #[cfg(test)]
 mod test {
 
-    use std::ops::Neg;
+    use core::ops::Neg;
 
     use crate::bn256::{Fr, G1Affine, G1};
     use ark_std::{end_timer, start_timer};
-    use ff::{Field, PrimeField};
-    use group::{Curve, Group};
+    use ff::{Field, PrimeField, WithSmallOrderMulGroup};
+    use group::{prime::PrimeCurveAffine, Curve, Group};
     use pasta_curves::arithmetic::CurveAffine;
-    use rand_core::OsRng;
+    use rand_core::{OsRng, RngCore};
 
     // keeping older implementation it here for baseline comparison, debugging & benchmarking
     fn best_multiexp<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
@@ -391,4 +603,215 @@ mod test {
         run_msm_cross::<G1Affine>(10, 18);
         // run_msm_cross::<G1Affine>(19, 23);
     }
+
+    #[test]
+    fn test_msm_small_scalars() {
+        // small scalars leave the upper buckets of every window empty
+        for bits in [1, 4, 16] {
+            for n in [1, 3, 10, 100, 1000] {
+                let points = (0..n)
+                    .map(|_| G1::random(OsRng).to_affine())
+                    .collect::<Vec<_>>();
+                let scalars = (0..n)
+                    .map(|_| Fr::from(OsRng.next_u64() % (1 << bits)))
+                    .collect::<Vec<_>>();
+
+                let naive = scalars
+                    .iter()
+                    .zip(points.iter())
+                    .fold(G1::identity(), |acc, (s, p)| acc + p * s);
+                assert_eq!(super::best_multiexp(&scalars, &points), naive);
+                assert_eq!(best_multiexp(&scalars, &points), naive);
+            }
+        }
+    }
+
+    #[test]
+    fn test_msm_glv() {
+        let max_k = 12;
+        let points = (0..1 << max_k)
+            .map(|_| G1::random(OsRng))
+            .collect::<Vec<_>>();
+        let mut affine_points = vec![G1Affine::identity(); 1 << max_k];
+        G1::batch_normalize(&points[..], &mut affine_points[..]);
+        let points = affine_points;
+
+        let scalars = (0..1 << max_k)
+            .map(|_| Fr::random(OsRng))
+            .collect::<Vec<_>>();
+
+        for n in [0, 1, 2, 3, 5, 17, 100] {
+            let e0 = super::best_multiexp(&scalars[..n], &points[..n]);
+            let e1 = super::best_multiexp_glv(&scalars[..n], &points[..n]);
+            assert_eq!(e0, e1);
+        }
+
+        for k in 4..=max_k {
+            let points = &points[..1 << k];
+            let scalars = &scalars[..1 << k];
+
+            let t0 = start_timer!(|| format!("w/o glv k={}", k));
+            let e0 = super::best_multiexp(scalars, points);
+            end_timer!(t0);
+
+            let t1 = start_timer!(|| format!("w/  glv k={}", k));
+            let e1 = super::best_multiexp_glv(scalars, points);
+            end_timer!(t1);
+
+            assert_eq!(e0, e1);
+        }
+
+        // edge scalars and the identity point
+        let scalars = [Fr::ZERO, Fr::ONE, -Fr::ONE, Fr::ZETA, -Fr::ZETA];
+        let mut points = points[..scalars.len()].to_vec();
+        points[1] = G1Affine::identity();
+        assert_eq!(
+            super::best_multiexp(&scalars, &points),
+            super::best_multiexp_glv(&scalars, &points)
+        );
+    }
+
+    #[test]
+    fn test_msm_projective() {
+        let mut points = (0..1000).map(|_| G1::random(OsRng)).collect::<Vec<_>>();
+        points[1] = G1::identity();
+        let scalars = (0..1000).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+
+        for n in [0, 1, 2, 3, 17, 1000] {
+            let affine_points = points[..n]
+                .iter()
+                .map(|p| p.to_affine())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                super::best_multiexp_projective(&scalars[..n], &points[..n]),
+                super::best_multiexp(&scalars[..n], &affine_points)
+            );
+        }
+    }
+
+    #[test]
+    fn test_msm_parallel_deterministic() {
+        let max_k = 12;
+        let points = (0..1 << max_k)
+            .map(|_| G1::random(OsRng))
+            .collect::<Vec<_>>();
+        let mut affine_points = vec![G1Affine::identity(); 1 << max_k];
+        G1::batch_normalize(&points[..], &mut affine_points[..]);
+        let points = affine_points;
+        let scalars = (0..1 << max_k)
+            .map(|_| Fr::random(OsRng))
+            .collect::<Vec<_>>();
+
+        for n in [0, 1, 7, 100, 1 << 10, 1 << max_k] {
+            let mut expected = G1::identity();
+            super::multiexp_serial(&scalars[..n], &points[..n], &mut expected);
+
+            for threads in [1, 2, 3, 8] {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .unwrap();
+                let e = pool.install(|| super::best_multiexp(&scalars[..n], &points[..n]));
+                assert_eq!(e, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_best_window() {
+        let float = |n: usize| (f64::from(n as u32)).ln().ceil() as usize;
+        let edges = super::EXP_FLOORS
+            .iter()
+            .flat_map(|&e| [e as usize - 1, e as usize, e as usize + 1]);
+        for n in (32..1 << 16)
+            .chain(edges.filter(|&n| n >= 32))
+            .chain([u32::MAX as usize])
+        {
+            assert_eq!(super::best_window(n), float(n), "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_msm_with_window() {
+        assert_eq!(super::best_window(0), 1);
+        assert_eq!(super::best_window(10), 3);
+        assert_eq!(super::best_window(1 << 16), 12);
+
+        let points = (0..1000)
+            .map(|_| G1::random(OsRng).to_affine())
+            .collect::<Vec<_>>();
+        let scalars = (0..1000).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+
+        for n in [1, 3, 10, 1000] {
+            let expected = super::best_multiexp(&scalars[..n], &points[..n]);
+            for window in 1..=16 {
+                assert_eq!(
+                    super::msm_with_window(&scalars[..n], &points[..n], window),
+                    expected
+                );
+            }
+        }
+
+        assert_eq!(
+            super::msm_with_window::<G1Affine>(&[], &[], 8),
+            G1::identity()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_msm_with_window_length_mismatch() {
+        let points = [G1Affine::generator(); 2];
+        super::msm_with_window(&[Fr::ONE], &points, 4);
+    }
+
+    #[cfg(feature = "debug-msm")]
+    #[test]
+    fn test_multiexp_debug() {
+        let points = (0..100)
+            .map(|_| G1::random(OsRng).to_affine())
+            .collect::<Vec<_>>();
+        let scalars = (0..100).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+        let expected = super::best_multiexp(&scalars, &points);
+
+        for c in [1, 3, 4, 8, 13] {
+            let (result, windows) = super::multiexp_debug(&scalars, &points, c);
+            assert_eq!(result, expected);
+            assert_eq!(windows.len(), Fr::NUM_BITS as usize / c + 1);
+        }
+    }
+
+    #[test]
+    fn test_random_linear_combination() {
+        let proofs = [0, 1, 5, 100, 3]
+            .iter()
+            .map(|n| {
+                (0..*n)
+                    .map(|_| (Fr::random(OsRng), G1::random(OsRng).to_affine()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let proofs = proofs.iter().map(|p| p.as_slice()).collect::<Vec<_>>();
+        let challenges = (0..proofs.len())
+            .map(|_| Fr::random(OsRng))
+            .collect::<Vec<_>>();
+
+        let expected =
+            proofs
+                .iter()
+                .zip(challenges.iter())
+                .fold(G1::identity(), |acc, (proof, challenge)| {
+                    let (coeffs, bases): (Vec<_>, Vec<_>) = proof.iter().copied().unzip();
+                    acc + super::best_multiexp(&coeffs, &bases) * challenge
+                });
+        assert_eq!(
+            super::random_linear_combination(&proofs, &challenges),
+            expected
+        );
+
+        assert_eq!(
+            super::random_linear_combination::<G1Affine>(&[], &[]),
+            G1::identity()
+        );
+    }
 }
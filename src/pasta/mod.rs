@@ -4,10 +4,10 @@ use crate::{
     arithmetic::{CurveEndo, EndoParameters},
     endo,
 };
+use core::convert::TryInto;
 use ff::PrimeField;
 use ff::WithSmallOrderMulGroup;
 pub use pasta_curves::{pallas, vesta, Ep, EpAffine, Eq, EqAffine, Fp, Fq};
-use std::convert::TryInto;
 
 // Generated using https://github.com/ConsenSys/gnark-crypto/blob/master/ecc/utils.go
 // with `pasta_curves::Fp::ZETA`
@@ -71,3 +71,43 @@ fn test_endo() {
         }
     }
 }
+
+// `CurveExt::hash_to_curve` for `Ep` (Pallas) and `Eq` (Vesta) is the one of
+// `pasta_curves`, the simplified SWU map onto the isogenous curves followed by
+// the isogeny, so hashes agree with that crate byte-for-byte.
+#[test]
+fn test_hash_to_curve() {
+    use crate::CurveExt;
+
+    // Test vectors of `pasta_curves`, for which the two SWU maps of each hash
+    // take opposite gx1 square / non-square branches on Pallas and Vesta.
+    let vectors: [(&[u8], [&str; 3]); 1] = [(
+        b"Trans rights now!",
+        [
+            "0x36a6e3a9c50b7b6540cb002c977c82f37f8a875fb51eb35327ee1452e6ce7947",
+            "0x01da3b4403d73252f2d7e9c19bc23dc6a080f2d02f8262fca4f7e3d756ac6a7c",
+            "0x1d48103df8fcbb70d1809c1806c95651dd884a559fec0549658537ce9d94bed9",
+        ],
+    )];
+    for (message, expected) in vectors {
+        let p = Ep::hash_to_curve("z.cash:test")(message);
+        let (x, y, z) = p.jacobian_coordinates();
+        assert_eq!([x, y, z].map(|c| format!("{:?}", c)), expected);
+        assert!(bool::from(p.is_on_curve()));
+    }
+
+    let vectors: [(&[u8], [&str; 3]); 1] = [(
+        b"hello",
+        [
+            "0x12763505036e0e1a6684b7a7d8d5afb7378cc2b191a95e34f44824a06fcbd08e",
+            "0x0256eafc0188b79bfa7c4b2b393893ddc298e90da500fa4a9aee17c2ea4240e6",
+            "0x1b58d4aa4d68c3f4d9916b77c79ff9911597a27f2ee46244e98eb9615172d2ad",
+        ],
+    )];
+    for (message, expected) in vectors {
+        let p = Eq::hash_to_curve("z.cash:test")(message);
+        let (x, y, z) = p.jacobian_coordinates();
+        assert_eq!([x, y, z].map(|c| format!("{:?}", c)), expected);
+        assert!(bool::from(p.is_on_curve()));
+    }
+}
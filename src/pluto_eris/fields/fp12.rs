@@ -568,4 +568,20 @@ mod test {
             0x2400000000002400,
         ]
     );
+    crate::field_testing_suite!(
+        Fp12,
+        "frobenius_coeffs",
+        // p: modulus of base field (Here, Fp::MODULUS)
+        [
+            0x9ffffcd300000001,
+            0xa2a7e8c30006b945,
+            0xe4a7a5fe8fadffd6,
+            0x443f9a5cda8a6c7b,
+            0xa803ca76f439266f,
+            0x0130e0000d7f70e4,
+            0x2400000000002400,
+        ],
+        super::super::fp6::V_CUBE,
+        [FROBENIUS_COEFF_FP12_C1, 1, 6]
+    );
 }
@@ -1,11 +1,12 @@
 use super::fp::{Fp, MODULUS_STR};
 use crate::ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
 use crate::ff_ext::Legendre;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::convert::TryInto;
+use core::ops::MulAssign;
 use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
-use std::cmp::Ordering;
-use std::ops::MulAssign;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "derive_serde")]
@@ -264,6 +265,14 @@ impl Fp2 {
         t
     }
 
+    /// Multiplies both components by the base field element `s`.
+    pub fn mul_by_base(&self, s: &Fp) -> Fp2 {
+        Self {
+            c0: self.c0 * s,
+            c1: self.c1 * s,
+        }
+    }
+
     pub fn square(&self) -> Self {
         let mut t = *self;
         t.square_assign();
@@ -329,6 +338,11 @@ impl Legendre for Fp2 {
     fn legendre(&self) -> i64 {
         self.norm().legendre()
     }
+
+    /// A square exactly when its norm is a square in the base field.
+    fn is_square(&self) -> Choice {
+        self.norm().is_square()
+    }
 }
 
 impl Field for Fp2 {
@@ -536,15 +550,19 @@ impl crate::serde::SerdeObject for Fp2 {
         }
         res
     }
+
+    #[cfg(feature = "std")]
     fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
         let [c0, c1] = [(); 2].map(|_| Fp::read_raw_unchecked(reader));
         Self { c0, c1 }
     }
+    #[cfg(feature = "std")]
     fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let c0 = Fp::read_raw(reader)?;
         let c1 = Fp::read_raw(reader)?;
         Ok(Self { c0, c1 })
     }
+    #[cfg(feature = "std")]
     fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         self.c0.write_raw(writer)?;
         self.c1.write_raw(writer)
@@ -574,6 +592,7 @@ mod test {
     crate::field_testing_suite!(Fp2, "conversion");
     crate::field_testing_suite!(Fp2, "serialization");
     crate::field_testing_suite!(Fp2, "quadratic_residue");
+    crate::field_testing_suite!(Fp2, "is_square");
     crate::field_testing_suite!(Fp2, "sqrt");
     crate::field_testing_suite!(Fp2, "zeta", Fp);
     // extension field-specific
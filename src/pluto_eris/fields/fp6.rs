@@ -286,7 +286,7 @@ impl Fp6 {
 
     /// Multiply by cubic nonresidue v.
     pub fn mul_by_nonresidue(&mut self) {
-        use std::mem::swap;
+        use core::mem::swap;
         swap(&mut self.c0, &mut self.c1);
         swap(&mut self.c0, &mut self.c2);
         // c0, c1, c2 -> c2, c0, c1
@@ -681,4 +681,21 @@ mod test {
             0x2400000000002400,
         ]
     );
+    crate::field_testing_suite!(
+        Fp6,
+        "frobenius_coeffs",
+        // p: modulus of base field (Here, Fp::MODULUS)
+        [
+            0x9ffffcd300000001,
+            0xa2a7e8c30006b945,
+            0xe4a7a5fe8fadffd6,
+            0x443f9a5cda8a6c7b,
+            0xa803ca76f439266f,
+            0x0130e0000d7f70e4,
+            0x2400000000002400,
+        ],
+        V_CUBE,
+        [FROBENIUS_COEFF_FP6_C1, 1, 3],
+        [FROBENIUS_COEFF_FP6_C2, 2, 3]
+    );
 }
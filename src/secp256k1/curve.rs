@@ -1,6 +1,9 @@
+use crate::arithmetic::{adc, mul_512, sbb, CurveEndo};
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
 use crate::group::{prime::PrimeCurveAffine, Curve, Group as _, GroupEncoding};
+#[cfg(feature = "sha2")]
+use crate::hash_to_curve::{hash_to_curve_sha256, sswu_map_to_curve};
 use crate::hash_to_curve::{sswu_hash_to_curve, sswu_hash_to_curve_secp256k1};
 use crate::secp256k1::Fp;
 use crate::secp256k1::Fq;
@@ -69,6 +72,23 @@ new_curve_impl!(
 );
 
 impl Secp256k1 {
+    /// Returns the `hash_to_curve` of the `secp256k1_XMD:SHA-256_SSWU_RO_`
+    /// suite of RFC 9380 under the domain separation tag `dst`.
+    ///
+    /// Unlike [`CurveExt::hash_to_curve`], which expands messages with
+    /// BLAKE2b, this agrees with other implementations of the suite.
+    ///
+    /// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-suites-for-secp256k1>
+    #[cfg(feature = "sha2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sha2")))]
+    pub fn hash_to_curve_sha256(dst: &[u8]) -> impl Fn(&[u8]) -> Self + '_ {
+        move |message| {
+            hash_to_curve_sha256(dst, message, |u| {
+                iso_map_secp256k1(sswu_map_to_curve(u, IsoSecp256k1::SSWU_Z))
+            })
+        }
+    }
+
     // Z = -11 (reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-suites-for-secp256k1>)
     // 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc24
     #[allow(dead_code)]
@@ -268,12 +288,182 @@ pub(crate) fn iso_map_secp256k1(rp: IsoSecp256k1) -> Secp256k1 {
     Secp256k1::new_jacobian(x, y, z).unwrap()
 }
 
+// Reduced basis of the lattice `{(a, b) : a - b * ZETA = 0 mod n}`, where `n`
+// is the order of the group, and `round(2^384 * b / n)` for its entries, as
+// in libsecp256k1. The lattice is wider than the one of BN254, so the 256 bit
+// precision of `EndoParameters` would let the halves reach 129 bits; 384 bits
+// with rounding keep them within 128 bits.
+const ENDO_G1: [u64; 4] = [
+    0x1571b4ae8ac47f71,
+    0x221208ac9df506c6,
+    0x6f547fa90abfe4c4,
+    0xe4437ed6010e8828,
+];
+const ENDO_G2: [u64; 4] = [
+    0xe893209a45dbb031,
+    0x3daa8a1471e8ca7f,
+    0xe86c90e49284eb15,
+    0x3086d221a7d46bcd,
+];
+const ENDO_B1: [u64; 4] = [0x6f547fa90abfe4c3, 0xe4437ed6010e8828, 0, 0];
+const ENDO_B2: [u64; 4] = [0xe86c90e49284eb15, 0x3086d221a7d46bcd, 0, 0];
+
+impl CurveEndo for Secp256k1 {
+    fn decompose_scalar(k: &Fq) -> (u128, bool, u128, bool) {
+        let to_limbs = |e: &Fq| {
+            let repr = e.to_repr();
+            [0, 1, 2, 3].map(|i| u64::from_le_bytes(repr[i * 8..(i + 1) * 8].try_into().unwrap()))
+        };
+
+        let get_lower_128 = |e: &Fq| {
+            let e = to_limbs(e);
+            u128::from(e[0]) | (u128::from(e[1]) << 64)
+        };
+
+        let is_neg = |e: &Fq| {
+            let e = to_limbs(e);
+            let (_, borrow) = sbb(0xffffffffffffffff, e[0], 0);
+            let (_, borrow) = sbb(0xffffffffffffffff, e[1], borrow);
+            let (_, borrow) = sbb(0xffffffffffffffff, e[2], borrow);
+            let (_, borrow) = sbb(0x00, e[3], borrow);
+            borrow & 1 != 0
+        };
+
+        // round(g * k / 2^384), which is below 2^128
+        let round_384 = |g: [u64; 4], k: [u64; 4]| {
+            let p = mul_512(g, k);
+            let (c0, carry) = adc(p[6], 0, p[5] >> 63);
+            let (c1, _) = adc(p[7], 0, carry);
+            [c0, c1, 0, 0]
+        };
+
+        let input = to_limbs(k);
+        let c1 = round_384(ENDO_G2, input);
+        let c2 = round_384(ENDO_G1, input);
+        // both products are below n
+        let q1 = mul_512(c1, ENDO_B1);
+        let q2 = mul_512(c2, ENDO_B2);
+        let q1 = Fq::from_raw([q1[0], q1[1], q1[2], q1[3]]);
+        let q2 = Fq::from_raw([q2[0], q2[1], q2[2], q2[3]]);
+        let k2 = q2 - q1;
+        let k1 = k + k2 * Fq::ZETA;
+        let k1_neg = is_neg(&k1);
+        let k2_neg = is_neg(&k2);
+        let k1 = if k1_neg { -k1 } else { k1 };
+        let k2 = if k2_neg { -k2 } else { k2 };
+
+        (get_lower_128(&k1), k1_neg, get_lower_128(&k2), k2_neg)
+    }
+}
+
+/// Multi-exponentiation over secp256k1 using its GLV endomorphism: every
+/// scalar is split into two halves of at most 128 bits, doubling the number
+/// of bases but halving the number of windows of the bucket method. See
+/// [`best_multiexp_glv`](crate::msm::best_multiexp_glv).
+///
+/// This function will panic if coeffs and bases have a different length.
+pub fn msm_glv(coeffs: &[Fq], bases: &[Secp256k1Affine]) -> Secp256k1 {
+    crate::msm::best_multiexp_glv(coeffs, bases)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     crate::curve_testing_suite!(Secp256k1);
     crate::curve_testing_suite!(Secp256k1, "endo_consistency");
+    crate::curve_testing_suite!(Secp256k1, "endo");
     crate::curve_testing_suite!(Secp256k1, "ecdsa_example");
+    crate::curve_testing_suite!(Secp256k1, "identity_encodings");
+    crate::curve_testing_suite!(Secp256k1, "double");
+    crate::curve_testing_suite!(Secp256k1, "hash_to_curve");
+    crate::curve_testing_suite!(Secp256k1, "default_identity");
+    crate::curve_testing_suite!(Secp256k1, "conditional_select");
+    crate::curve_testing_suite!(Secp256k1, "sum");
+    crate::curve_testing_suite!(Secp256k1, "from_xy");
+    crate::curve_testing_suite!(Secp256k1, "from_affine");
+    crate::curve_testing_suite!(
+        Secp256k1,
+        "sswu_map_to_curve",
+        |u| iso_map_secp256k1(crate::hash_to_curve::sswu_map_to_curve(
+            u,
+            IsoSecp256k1::SSWU_Z
+        )),
+        // Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-secp256k1_xmdsha-256_sswu_r>
+        [
+            // msg = ""
+            (
+                [
+                    "6b0f9910dd2ba71c78f2ee9f04d73b5f4c5f7fc773a701abea1e573cab002fb3",
+                    "1ae6c212e08fe1a5937f6202f929a2cc8ef4ee5b9782db68b0d5799fd8f09e16",
+                ],
+                [
+                    (
+                        "74519ef88b32b425a095e4ebcc84d81b64e9e2c2675340a720bb1a1857b99f1e",
+                        "c174fa322ab7c192e11748beed45b508e9fdb1ce046dee9c2cd3a2a86b410936",
+                    ),
+                    (
+                        "44548adb1b399263ded3510554d28b4bead34b8cf9a37b4bd0bd2ba4db87ae63",
+                        "96eb8e2faf05e368efe5957c6167001760233e6dd2487516b46ae725c4cce0c6",
+                    ),
+                ],
+                (
+                    "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                    "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+                ),
+            ),
+            // msg = "abc"
+            (
+                [
+                    "128aab5d3679a1f7601e3bdf94ced1f43e491f544767e18a4873f397b08a2b61",
+                    "5897b65da3b595a813d0fdcc75c895dc531be76a03518b044daaa0f2e4689e00",
+                ],
+                [
+                    (
+                        "07dd9432d426845fb19857d1b3a91722436604ccbbbadad8523b8fc38a5322d7",
+                        "604588ef5138cffe3277bbd590b8550bcbe0e523bbaf1bed4014a467122eb33f",
+                    ),
+                    (
+                        "e9ef9794d15d4e77dde751e06c182782046b8dac05f8491eb88764fc65321f78",
+                        "cb07ce53670d5314bf236ee2c871455c562dd76314aa41f012919fe8e7f717b3",
+                    ),
+                ],
+                (
+                    "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                    "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+                ),
+            ),
+        ]
+    );
+    #[cfg(feature = "sha2")]
+    crate::curve_testing_suite!(
+        Secp256k1,
+        "hash_to_curve_vectors",
+        Secp256k1::hash_to_curve_sha256(b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_"),
+        // Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-secp256k1_xmdsha-256_sswu_r>
+        [
+            (
+                "",
+                (
+                    "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                    "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+                ),
+            ),
+            (
+                "abc",
+                (
+                    "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                    "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+                ),
+            ),
+            (
+                "abcdef0123456789",
+                (
+                    "bac54083f293f1fe08e4a70137260aa90783a5cb84d3f35848b324d0674b0e3a",
+                    "4436476085d4c3c4508b60fcf4389c40176adce756b398bdee27bca19758d828",
+                ),
+            ),
+        ]
+    );
     crate::curve_testing_suite!(
         Secp256k1,
         "constants",
@@ -284,4 +474,28 @@ mod test {
         SECP_GENERATOR_Y,
         Fq::MODULUS
     );
+
+    #[test]
+    fn test_msm_glv() {
+        use crate::msm::best_multiexp;
+        use rand_core::OsRng;
+
+        let n = 1 << 10;
+        let bases = (0..n)
+            .map(|_| Secp256k1::random(OsRng).to_affine())
+            .collect::<Vec<_>>();
+        let coeffs = (0..n).map(|_| Fq::random(OsRng)).collect::<Vec<_>>();
+        for n in [0, 1, 2, 3, 5, 17, 100, n] {
+            assert_eq!(
+                msm_glv(&coeffs[..n], &bases[..n]),
+                best_multiexp(&coeffs[..n], &bases[..n])
+            );
+        }
+
+        // edge scalars and the identity point
+        let coeffs = [Fq::ZERO, Fq::ONE, -Fq::ONE, Fq::ZETA, -Fq::ZETA];
+        let mut bases = bases[..coeffs.len()].to_vec();
+        bases[1] = Secp256k1Affine::identity();
+        assert_eq!(msm_glv(&coeffs, &bases), best_multiexp(&coeffs, &bases));
+    }
 }
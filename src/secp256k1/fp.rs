@@ -294,12 +294,31 @@ extend_field_legendre!(Fp);
 mod test {
     use super::*;
     crate::field_testing_suite!(Fp, "field_arithmetic");
+    crate::field_testing_suite!(Fp, "mul_add");
     crate::field_testing_suite!(Fp, "conversion");
     crate::field_testing_suite!(Fp, "serialization");
     crate::field_testing_suite!(Fp, "quadratic_residue");
+    crate::field_testing_suite!(Fp, "is_square");
     crate::field_testing_suite!(Fp, "bits");
+    crate::field_testing_suite!(Fp, "bits_cross_width");
+    crate::field_testing_suite!(Fp, "ct_less_than_power_of_two");
+    crate::field_testing_suite!(Fp, "from_repr_slice");
+    crate::field_testing_suite!(Fp, "serialized_size");
+    crate::field_testing_suite!(Fp, "mul_by_u64");
+    crate::field_testing_suite!(Fp, "conditional_select_slice");
+    crate::field_testing_suite!(Fp, "batch_invert");
+    crate::field_testing_suite!(Fp, "pow_fixed_window");
+    crate::field_testing_suite!(Fp, "try_from_bytes");
+    crate::field_testing_suite!(Fp, "sqrt_alt");
+    crate::field_testing_suite!(Fp, "sub_borrow");
+    crate::field_testing_suite!(Fp, "halve");
+    crate::field_testing_suite!(Fp, "bytes_be");
+    crate::field_testing_suite!(Fp, "pow_ct");
+    crate::field_testing_suite!(Fp, "le_bits_array");
+    crate::field_testing_suite!(Fp, "from_raw_checked");
     crate::field_testing_suite!(Fp, "serialization_check");
     crate::field_testing_suite!(Fp, "constants", MODULUS_STR);
     crate::field_testing_suite!(Fp, "sqrt");
+    crate::field_testing_suite!(Fp, "sqrt_canonical");
     crate::field_testing_suite!(Fp, "zeta");
 }
@@ -301,12 +301,31 @@ extend_field_legendre!(Fq);
 mod test {
     use super::*;
     crate::field_testing_suite!(Fq, "field_arithmetic");
+    crate::field_testing_suite!(Fq, "mul_add");
     crate::field_testing_suite!(Fq, "conversion");
     crate::field_testing_suite!(Fq, "serialization");
     crate::field_testing_suite!(Fq, "quadratic_residue");
+    crate::field_testing_suite!(Fq, "is_square");
     crate::field_testing_suite!(Fq, "bits");
+    crate::field_testing_suite!(Fq, "bits_cross_width");
+    crate::field_testing_suite!(Fq, "ct_less_than_power_of_two");
+    crate::field_testing_suite!(Fq, "from_repr_slice");
+    crate::field_testing_suite!(Fq, "serialized_size");
+    crate::field_testing_suite!(Fq, "mul_by_u64");
+    crate::field_testing_suite!(Fq, "conditional_select_slice");
+    crate::field_testing_suite!(Fq, "batch_invert");
+    crate::field_testing_suite!(Fq, "pow_fixed_window");
+    crate::field_testing_suite!(Fq, "try_from_bytes");
+    crate::field_testing_suite!(Fq, "sqrt_alt");
+    crate::field_testing_suite!(Fq, "sub_borrow");
+    crate::field_testing_suite!(Fq, "halve");
+    crate::field_testing_suite!(Fq, "bytes_be");
+    crate::field_testing_suite!(Fq, "pow_ct");
+    crate::field_testing_suite!(Fq, "le_bits_array");
+    crate::field_testing_suite!(Fq, "from_raw_checked");
     crate::field_testing_suite!(Fq, "serialization_check");
     crate::field_testing_suite!(Fq, "constants", MODULUS_STR);
     crate::field_testing_suite!(Fq, "sqrt");
+    crate::field_testing_suite!(Fq, "sqrt_canonical");
     crate::field_testing_suite!(Fq, "zeta");
 }
@@ -2,6 +2,8 @@ use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
 use crate::group::{prime::PrimeCurveAffine, Curve, Group as _, GroupEncoding};
 use crate::hash_to_curve::sswu_hash_to_curve;
+#[cfg(feature = "sha2")]
+use crate::hash_to_curve::{hash_to_curve_sha256, sswu_map_to_curve};
 use crate::secp256r1::Fp;
 use crate::secp256r1::Fq;
 use crate::{Coordinates, CurveAffine, CurveExt};
@@ -80,6 +82,21 @@ new_curve_impl!(
 );
 
 impl Secp256r1 {
+    /// Returns the `hash_to_curve` of the `P256_XMD:SHA-256_SSWU_RO_` suite of
+    /// RFC 9380 under the domain separation tag `dst`.
+    ///
+    /// Unlike [`CurveExt::hash_to_curve`], which expands messages with
+    /// BLAKE2b, this agrees with other implementations of the suite.
+    ///
+    /// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-suites-for-nist-p-256>
+    #[cfg(feature = "sha2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sha2")))]
+    pub fn hash_to_curve_sha256(dst: &[u8]) -> impl Fn(&[u8]) -> Self + '_ {
+        move |message| {
+            hash_to_curve_sha256(dst, message, |u| sswu_map_to_curve(u, Secp256r1::SSVDW_Z))
+        }
+    }
+
     // Optimal Z with: <https://datatracker.ietf.org/doc/html/rfc9380#sswu-z-code>
     // 0xffffffff00000001000000000000000000000000fffffffffffffffffffffff5
     // Z = -10 (reference: <https://www.rfc-editor.org/rfc/rfc9380.html#section-8.2>)
@@ -96,6 +113,92 @@ mod test {
     use super::*;
     crate::curve_testing_suite!(Secp256r1);
     crate::curve_testing_suite!(Secp256r1, "ecdsa_example");
+    crate::curve_testing_suite!(Secp256r1, "hash_to_curve");
+    crate::curve_testing_suite!(Secp256r1, "default_identity");
+    crate::curve_testing_suite!(Secp256r1, "conditional_select");
+    crate::curve_testing_suite!(Secp256r1, "sum");
+    crate::curve_testing_suite!(Secp256r1, "from_xy");
+    crate::curve_testing_suite!(Secp256r1, "from_affine");
+    crate::curve_testing_suite!(
+        Secp256r1,
+        "sswu_map_to_curve",
+        |u| crate::hash_to_curve::sswu_map_to_curve(u, Secp256r1::SSVDW_Z),
+        // Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-p256_xmdsha-256_sswu_ro_>
+        [
+            // msg = ""
+            (
+                [
+                    "ad5342c66a6dd0ff080df1da0ea1c04b96e0330dd89406465eeba11582515009",
+                    "8c0f1d43204bd6f6ea70ae8013070a1518b43873bcd850aafa0a9e220e2eea5a",
+                ],
+                [
+                    (
+                        "ab640a12220d3ff283510ff3f4b1953d09fad35795140b1c5d64f313967934d5",
+                        "dccb558863804a881d4fff3455716c836cef230e5209594ddd33d85c565b19b1",
+                    ),
+                    (
+                        "51cce63c50d972a6e51c61334f0f4875c9ac1cd2d3238412f84e31da7d980ef5",
+                        "b45d1a36d00ad90e5ec7840a60a4de411917fbe7c82c3949a6e699e5a1b66aac",
+                    ),
+                ],
+                (
+                    "2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4",
+                    "8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415",
+                ),
+            ),
+            // msg = "abc"
+            (
+                [
+                    "afe47f2ea2b10465cc26ac403194dfb68b7f5ee865cda61e9f3e07a537220af1",
+                    "379a27833b0bfe6f7bdca08e1e83c760bf9a338ab335542704edcd69ce9e46e0",
+                ],
+                [
+                    (
+                        "5219ad0ddef3cc49b714145e91b2f7de6ce0a7a7dc7406c7726c7e373c58cb48",
+                        "7950144e52d30acbec7b624c203b1996c99617d0b61c2442354301b191d93ecf",
+                    ),
+                    (
+                        "019b7cb4efcfeaf39f738fe638e31d375ad6837f58a852d032ff60c69ee3875f",
+                        "589a62d2b22357fed5449bc38065b760095ebe6aeac84b01156ee4252715446e",
+                    ),
+                ],
+                (
+                    "0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f",
+                    "5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e",
+                ),
+            ),
+        ]
+    );
+    #[cfg(feature = "sha2")]
+    crate::curve_testing_suite!(
+        Secp256r1,
+        "hash_to_curve_vectors",
+        Secp256r1::hash_to_curve_sha256(b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_"),
+        // Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-p256_xmdsha-256_sswu_ro_>
+        [
+            (
+                "",
+                (
+                    "2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4",
+                    "8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415",
+                ),
+            ),
+            (
+                "abc",
+                (
+                    "0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f",
+                    "5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e",
+                ),
+            ),
+            (
+                "abcdef0123456789",
+                (
+                    "65038ac8f2b1def042a5df0b33b1f4eca6bff7cb0f9c6c1526811864e544ed80",
+                    "cad44d40a656e7aff4002a8de287abc8ae0482b5ae825822bb870d6df9b56ca3",
+                ),
+            ),
+        ]
+    );
     crate::curve_testing_suite!(
         Secp256r1,
         "constants",
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use std::io::{self, Read, Write};
 
 /// Trait for converting raw bytes to/from the internal representation of a type.
@@ -18,8 +20,11 @@ pub trait SerdeObject: Sized {
     /// to ensure the bytes represent a valid object. This function should only be used
     /// internally when some machine state cannot be kept in memory (e.g., between runs)
     /// and needs to be reloaded as quickly as possible.
+    #[cfg(feature = "std")]
     fn read_raw_unchecked<R: Read>(reader: &mut R) -> Self;
+    #[cfg(feature = "std")]
     fn read_raw<R: Read>(reader: &mut R) -> io::Result<Self>;
 
+    #[cfg(feature = "std")]
     fn write_raw<W: Write>(&self, writer: &mut W) -> io::Result<()>;
 }
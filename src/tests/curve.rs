@@ -197,9 +197,9 @@ macro_rules! curve_testing_suite {
                 let b = a.double();
                 let c = b.double();
 
-                for a_identity in (0..1).map(|n| n == 1) {
-                    for b_identity in (0..1).map(|n| n == 1) {
-                        for c_identity in (0..1).map(|n| n == 1) {
+                for a_identity in (0..2).map(|n| n == 1) {
+                    for b_identity in (0..2).map(|n| n == 1) {
+                        for c_identity in (0..2).map(|n| n == 1) {
                             let mut v = [a, b, c];
                             if a_identity {
                                 v[0] = $c::identity()
@@ -228,6 +228,38 @@ macro_rules! curve_testing_suite {
                         }
                     }
                 }
+
+                // several points at infinity, including ones with nonzero x and y
+                let identities = [
+                    $c::identity(),
+                    $c::generator() - $c::generator(),
+                    $c::random(OsRng) * <$c as CurveExt>::ScalarExt::ZERO,
+                ];
+                for p in identities.iter() {
+                    assert!(bool::from(p.is_identity()));
+                }
+                let mut v = (0..20).map(|_| $c::random(OsRng)).collect::<Vec<_>>();
+                for (i, p) in identities.iter().enumerate() {
+                    v[0] = *p;
+                    v[7 + i] = *p;
+                    v[19] = *p;
+                }
+                let mut t = vec![<$c as CurveExt>::AffineExt::generator(); v.len()];
+                $c::batch_normalize(&v, &mut t);
+                for (p, q) in v.iter().zip(t.iter()) {
+                    assert_eq!(*q, p.to_affine());
+                    if bool::from(p.is_identity()) {
+                        assert_eq!(*q, <$c as CurveExt>::AffineExt::identity());
+                    }
+                }
+
+                // only points at infinity
+                let v = identities;
+                let mut t = [<$c as CurveExt>::AffineExt::generator(); 3];
+                $c::batch_normalize(&v, &mut t);
+                assert_eq!(t, [<$c as CurveExt>::AffineExt::identity(); 3]);
+
+                $c::batch_normalize(&[], &mut []);
             }
         }
 
@@ -270,18 +302,24 @@ macro_rules! curve_testing_suite {
                     let projective_bytes = projective_point.to_raw_bytes();
                     let projective_point_rec = $c::from_raw_bytes(&projective_bytes).unwrap();
                     assert_eq!(projective_point, projective_point_rec);
-                    let mut buf = Vec::new();
-                    projective_point.write_raw(&mut buf).unwrap();
-                    let projective_point_rec = $c::read_raw(&mut &buf[..]).unwrap();
-                    assert_eq!(projective_point, projective_point_rec);
+                    #[cfg(feature = "std")]
+                    {
+                        let mut buf = Vec::new();
+                        projective_point.write_raw(&mut buf).unwrap();
+                        let projective_point_rec = $c::read_raw(&mut &buf[..]).unwrap();
+                        assert_eq!(projective_point, projective_point_rec);
+                    }
 
                     let affine_bytes = affine_point.to_raw_bytes();
                     let affine_point_rec = <$c as CurveExt>::AffineExt::from_raw_bytes(&affine_bytes).unwrap();
                     assert_eq!(affine_point, affine_point_rec);
-                    let mut buf = Vec::new();
-                    affine_point.write_raw(&mut buf).unwrap();
-                    let affine_point_rec = <$c as CurveExt>::AffineExt::read_raw(&mut &buf[..]).unwrap();
-                    assert_eq!(affine_point, affine_point_rec);
+                    #[cfg(feature = "std")]
+                    {
+                        let mut buf = Vec::new();
+                        affine_point.write_raw(&mut buf).unwrap();
+                        let affine_point_rec = <$c as CurveExt>::AffineExt::read_raw(&mut &buf[..]).unwrap();
+                        assert_eq!(affine_point, affine_point_rec);
+                    }
                 }
             }
         }
@@ -321,10 +359,10 @@ macro_rules! curve_testing_suite {
             }
         }
 
-        use crate::ff::Field;
-        use crate::group::prime::PrimeCurveAffine;
-        use crate::{group::GroupEncoding, serde::SerdeObject};
-        use crate::{CurveAffine, CurveExt};
+        use $crate::ff::Field;
+        use $crate::group::prime::PrimeCurveAffine;
+        use $crate::{group::GroupEncoding, serde::SerdeObject};
+        use $crate::{CurveAffine, CurveExt};
         use rand_core::OsRng;
 
         #[test]
@@ -376,6 +414,345 @@ macro_rules! curve_testing_suite {
         }
     };
 
+    ($($curve: ident),*, "mul_reference") => {
+        #[test]
+        fn test_mul_reference() {
+            use rand_core::OsRng;
+            $({
+                type Scalar = <$curve as CurveExt>::ScalarExt;
+
+                for _ in 0..1000 {
+                    let point = $curve::random(OsRng);
+                    let scalar = Scalar::random(OsRng);
+                    assert_eq!(point * scalar, point.mul_reference(&scalar));
+                }
+
+                for scalar in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE] {
+                    let point = $curve::random(OsRng);
+                    assert_eq!(point * scalar, point.mul_reference(&scalar));
+                    assert_eq!(
+                        $curve::identity().mul_reference(&scalar),
+                        $curve::identity()
+                    );
+                }
+            })*
+        }
+    };
+
+    ($($curve: ident),*, "mul_by_geometric") => {
+        #[test]
+        fn test_mul_by_geometric() {
+            use rand_core::OsRng;
+            $({
+                type Scalar = <$curve as CurveExt>::ScalarExt;
+
+                let base = $curve::random(OsRng);
+                let start = Scalar::random(OsRng);
+                let ratio = Scalar::random(OsRng);
+                let n = 100;
+
+                let points = $curve::mul_by_geometric(&base, &start, &ratio, n);
+                assert_eq!(points.len(), n);
+                for (i, point) in points.iter().enumerate() {
+                    assert_eq!(*point, base * (start * ratio.pow_vartime([i as u64])));
+                }
+
+                assert!($curve::mul_by_geometric(&base, &start, &ratio, 0).is_empty());
+
+                let scalars = [Scalar::ZERO, Scalar::ONE, -Scalar::ONE, Scalar::random(OsRng)];
+                for (point, scalar) in base.mul_by_scalar_slice(&scalars).iter().zip(scalars.iter()) {
+                    assert_eq!(*point, base * scalar);
+                }
+                assert!($curve::identity()
+                    .mul_by_scalar_slice(&scalars)
+                    .iter()
+                    .all(|point| bool::from(point.is_identity())));
+            })*
+        }
+    };
+
+    ($($curve: ident),*, "default_identity") => {
+        #[test]
+        fn test_default_identity() {
+            use rand_core::OsRng;
+
+            // `is_identity` is reachable through the `CurveExt: Group` supertrait
+            fn check<C: CurveExt + Default>() {
+                let zero = C::default();
+                assert!(bool::from(zero.is_identity()));
+                assert_eq!(zero, C::identity());
+
+                for _ in 0..10 {
+                    let p = C::random(OsRng);
+                    assert!(!bool::from(p.is_identity()));
+                    assert_eq!(p + C::default(), p);
+                    assert_eq!(C::default() + p, p);
+                }
+            }
+
+            $({
+                check::<$curve>();
+                assert!(bool::from(<$curve as CurveExt>::AffineExt::default().is_identity()));
+            })*
+        }
+    };
+
+    ($($curve: ident),*, "conditional_select") => {
+        #[test]
+        fn test_conditional_select() {
+            use rand_core::OsRng;
+            use subtle::{Choice, ConditionallySelectable};
+
+            fn check<C: CurveExt + ConditionallySelectable>()
+            where
+                C::AffineExt: ConditionallySelectable,
+            {
+                let points = [C::random(OsRng), C::random(OsRng), C::identity()];
+                for a in points.iter() {
+                    for b in points.iter() {
+                        assert_eq!(C::conditional_select(a, b, Choice::from(0)), *a);
+                        assert_eq!(C::conditional_select(a, b, Choice::from(1)), *b);
+
+                        let (a, b) = (a.to_affine(), b.to_affine());
+                        let select = C::AffineExt::conditional_select;
+                        assert_eq!(select(&a, &b, Choice::from(0)), a);
+                        assert_eq!(select(&a, &b, Choice::from(1)), b);
+
+                        let (mut c, mut d) = (a, b);
+                        C::AffineExt::conditional_swap(&mut c, &mut d, Choice::from(1));
+                        assert_eq!((c, d), (b, a));
+                    }
+                }
+            }
+
+            $(check::<$curve>();)*
+        }
+    };
+
+    ($($curve: ident),*, "sum") => {
+        #[test]
+        fn test_sum() {
+            use rand_core::OsRng;
+
+            fn check<C: CurveExt + core::iter::Sum + for<'a> core::iter::Sum<&'a C>>() {
+                assert_eq!(core::iter::empty::<C>().sum::<C>(), C::identity());
+                assert_eq!([].iter().sum::<C>(), C::identity());
+
+                for n in [1, 2, 10] {
+                    let points = (0..n).map(|_| C::random(OsRng)).collect::<Vec<_>>();
+                    let expected = points.iter().fold(C::identity(), |acc, p| acc + p);
+                    assert_eq!(points.iter().sum::<C>(), expected);
+                    assert_eq!(points.into_iter().sum::<C>(), expected);
+                }
+            }
+
+            $(check::<$curve>();)*
+        }
+    };
+
+    ($($curve: ident),*, "from_xy") => {
+        #[test]
+        fn test_from_xy() {
+            use rand_core::OsRng;
+
+            $({
+                type Affine = <$curve as CurveExt>::AffineExt;
+                for _ in 0..10 {
+                    let p = $curve::random(OsRng).to_affine();
+                    let (x, y) = (p.x, p.y);
+                    assert_eq!(Affine::from_xy(x, y).unwrap(), p);
+                    assert_eq!(Affine::from_xy_unchecked(x, y), p);
+
+                    // off the curve
+                    let y = y + <$curve as CurveExt>::Base::ONE;
+                    assert!(bool::from(Affine::from_xy(x, y).is_none()));
+                    let q = Affine::from_xy_unchecked(x, y);
+                    assert_eq!((q.x, q.y), (x, y));
+                    assert!(bool::from(!q.is_on_curve()));
+                }
+
+                let zero = <$curve as CurveExt>::Base::ZERO;
+                assert_eq!(Affine::from_xy(zero, zero).unwrap(), Affine::identity());
+            })*
+        }
+    };
+
+    ($($curve: ident),*, "from_affine") => {
+        #[test]
+        fn test_from_affine() {
+            use rand_core::OsRng;
+
+            $({
+                type Affine = <$curve as CurveExt>::AffineExt;
+                let points = (0..10)
+                    .map(|_| $curve::random(OsRng))
+                    .chain([$curve::identity(), $curve::generator()]);
+                for p in points {
+                    let affine = Affine::from(p);
+                    assert_eq!(affine, p.to_affine());
+                    assert_eq!(Affine::from(&p), affine);
+                    assert_eq!(bool::from(affine.is_identity()), bool::from(p.is_identity()));
+
+                    let projective = $curve::from(affine);
+                    assert_eq!(projective, p);
+                    assert_eq!($curve::from(&affine), p);
+                    // Z = 1 for every finite point
+                    if !bool::from(p.is_identity()) {
+                        assert_eq!(projective.z, <$curve as CurveExt>::Base::ONE);
+                    }
+                }
+            })*
+        }
+    };
+
+    ($($curve: ident),*, "double") => {
+        #[test]
+        fn test_double_generic() {
+            use rand_core::OsRng;
+
+            // `double` is reachable through the `CurveExt: Group` supertrait
+            fn double<C: CurveExt>(p: &C) -> C {
+                p.double()
+            }
+
+            $({
+                let identity = $curve::identity();
+                assert_eq!(double(&identity), identity + identity);
+                assert!(bool::from(double(&identity).is_identity()));
+
+                let generator = $curve::generator();
+                assert_eq!(double(&generator), generator + generator);
+
+                for _ in 0..100 {
+                    let p = $curve::random(OsRng);
+                    assert_eq!(double(&p), p + p);
+                    assert_eq!(double(&p), p.double());
+                }
+            })*
+        }
+    };
+
+    ($($curve: ident),*, "affine_neg") => {
+        #[test]
+        fn test_affine_neg() {
+            use rand_core::OsRng;
+            use std::ops::Neg;
+            $({
+                type Affine = <$curve as CurveExt>::AffineExt;
+
+                // both the owned and the reference variant are implemented
+                fn neg_owned<T: Neg<Output = Affine>>(p: T) -> Affine {
+                    -p
+                }
+                fn neg_ref<'a, T: 'a>(p: &'a T) -> Affine
+                where
+                    &'a T: Neg<Output = Affine>,
+                {
+                    -p
+                }
+
+                let identity = Affine::identity();
+                assert_eq!(-identity, identity);
+                assert_eq!(-&identity, identity);
+
+                for _ in 0..100 {
+                    let p: Affine = $curve::random(OsRng).into();
+                    assert_eq!(neg_owned(p), neg_ref(&p));
+                    assert_eq!(-(-p), p);
+                    assert_eq!(-(-&p), p);
+                    assert_eq!((p + (-p)).to_affine(), identity);
+                    assert_eq!((p + (-&p)).to_affine(), identity);
+                    assert_eq!((-p).to_curve(), -(p.to_curve()));
+                }
+            })*
+        }
+    };
+
+    ($($curve: ident),*, "scalar_mul_operators") => {
+        #[test]
+        fn test_scalar_mul_operators() {
+            use rand_core::OsRng;
+            use std::ops::{Mul, MulAssign};
+
+            // generic code bounded on multiplication by a scalar reference
+            fn mul_by_ref<'a, P, S>(p: P, s: &'a S) -> <P as Mul<&'a S>>::Output
+            where
+                P: Mul<&'a S>,
+            {
+                p * s
+            }
+
+            $({
+                type Scalar = <$curve as CurveExt>::ScalarExt;
+                type Affine = <$curve as CurveExt>::AffineExt;
+
+                for _ in 0..10 {
+                    let p = $curve::random(OsRng);
+                    let s = Scalar::random(OsRng);
+                    let expected = p * s;
+
+                    assert_eq!(p * &s, expected);
+                    assert_eq!(&p * s, expected);
+                    assert_eq!(&p * &s, expected);
+                    assert_eq!(mul_by_ref(p, &s), expected);
+                    assert_eq!(mul_by_ref(&p, &s), expected);
+
+                    let a: Affine = p.into();
+                    assert_eq!(a * s, expected);
+                    assert_eq!(a * &s, expected);
+                    assert_eq!(&a * s, expected);
+                    assert_eq!(&a * &s, expected);
+
+                    let mut q = p;
+                    q.mul_assign(s);
+                    assert_eq!(q, expected);
+                    let mut q = p;
+                    q *= &s;
+                    assert_eq!(q, expected);
+                }
+            })*
+        }
+    };
+
+    ($($curve: ident),*, "identity_encodings") => {
+        #[test]
+        fn test_identity_encodings() {
+            use group::UncompressedEncoding;
+            $({
+                type Affine = <$curve as CurveExt>::AffineExt;
+
+                let identity = Affine::identity();
+                let compressed = identity.to_bytes();
+                let uncompressed = identity.to_uncompressed();
+
+                // the compressed infinity flag with either sign bit
+                let mut flipped_sign = compressed;
+                let last = flipped_sign.as_ref().len() - 1;
+                flipped_sign.as_mut()[last] ^= 0b0100_0000;
+
+                // all zero coordinates without the infinity flag
+                let mut no_flag = uncompressed;
+                let last = no_flag.as_ref().len() - 1;
+                no_flag.as_mut()[last] &= !0b0100_0000;
+
+                let decoded = [
+                    Affine::from_bytes(&compressed).unwrap(),
+                    Affine::from_bytes(&flipped_sign).unwrap(),
+                    Affine::from_uncompressed(&uncompressed).unwrap(),
+                    Affine::from_uncompressed_unchecked(&no_flag).unwrap(),
+                    $curve::identity().to_affine(),
+                    ($curve::generator() - $curve::generator()).to_affine(),
+                ];
+                for p in decoded.iter() {
+                    assert!(bool::from(p.is_identity()));
+                    assert_eq!(*p, identity);
+                    assert_eq!(identity, *p);
+                    assert_ne!(*p, Affine::generator());
+                }
+            })*
+        }
+    };
+
     ($($curve: ident),*, "endo_consistency") => {
         #[test]
         fn test_endo_consistency() {
@@ -483,8 +860,8 @@ macro_rules! curve_testing_suite {
     ($curve: ident, "svdw_map_to_curve", ($precomputed_constants: expr, $test_vector: expr)) => {
         #[test]
         fn test_map_to_curve() {
-            use crate::ff_ext::Legendre;
-            use crate::{hash_to_curve, CurveAffine, CurveExt};
+            use $crate::ff_ext::Legendre;
+            use $crate::{hash_to_curve, CurveAffine, CurveExt};
             use ff::PrimeField;
             use num_bigint::BigUint;
             use num_traits::Num;
@@ -521,6 +898,63 @@ macro_rules! curve_testing_suite {
         }
     };
 
+    ($curve: ident, "sswu_map_to_curve", $map_to_curve: expr, $test_vector: expr) => {
+        #[test]
+        fn test_sswu_map_to_curve() {
+            use $crate::CurveExt;
+            use ff::PrimeField;
+            use num_bigint::BigUint;
+            use num_traits::Num;
+
+            type Base = <$curve as CurveExt>::Base;
+
+            fn fe_from_hex(hex: &str) -> Base {
+                Base::from_str_vartime(&BigUint::from_str_radix(hex, 16).unwrap().to_string())
+                    .unwrap()
+            }
+
+            fn point_from_hex((x, y): (&str, &str)) -> <$curve as CurveExt>::AffineExt {
+                <$curve as CurveExt>::AffineExt::from_xy(fe_from_hex(x), fe_from_hex(y)).unwrap()
+            }
+
+            let map_to_curve: fn(Base) -> $curve = $map_to_curve;
+            let test_vector: &[([&str; 2], [(&str, &str); 2], (&str, &str))] = &$test_vector;
+            for (us, qs, p) in test_vector.iter() {
+                let [q0, q1] = us.map(|u| map_to_curve(fe_from_hex(u)));
+                assert_eq!(q0.to_affine(), point_from_hex(qs[0]));
+                assert_eq!(q1.to_affine(), point_from_hex(qs[1]));
+                assert_eq!((q0 + q1).to_affine(), point_from_hex(*p));
+            }
+        }
+    };
+
+    ($curve: ident, "hash_to_curve_vectors", $hash_to_curve: expr, $test_vector: expr) => {
+        #[test]
+        fn test_hash_to_curve_vectors() {
+            use $crate::CurveExt;
+            use ff::PrimeField;
+            use num_bigint::BigUint;
+            use num_traits::Num;
+
+            type Base = <$curve as CurveExt>::Base;
+
+            fn fe_from_hex(hex: &str) -> Base {
+                Base::from_str_vartime(&BigUint::from_str_radix(hex, 16).unwrap().to_string())
+                    .unwrap()
+            }
+
+            let hash_to_curve = $hash_to_curve;
+            let test_vector: &[(&str, (&str, &str))] = &$test_vector;
+            for (message, (x, y)) in test_vector.iter() {
+                let p: $curve = hash_to_curve(message.as_bytes());
+                let expected =
+                    <$curve as CurveExt>::AffineExt::from_xy(fe_from_hex(x), fe_from_hex(y))
+                        .unwrap();
+                assert_eq!(p.to_affine(), expected);
+            }
+        }
+    };
+
     ($curve: ident, "constants", $p: expr, $a: expr, $b: expr, $gen_x: expr, $gen_y: expr, $order: expr) => {
         #[test]
         #[allow(non_snake_case)]
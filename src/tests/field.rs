@@ -218,6 +218,28 @@ macro_rules! field_testing_suite {
         }
     };
 
+    ($field: ident, "mul_add") => {
+        #[test]
+        fn test_mul_add() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+            for _ in 0..1000000 {
+                let a = $field::random(&mut rng);
+                let b = $field::random(&mut rng);
+                let c = $field::random(&mut rng);
+                assert_eq!(a.mul_add(&b, &c), a * b + c);
+            }
+
+            // edge cases around the modulus
+            let max = -$field::ONE;
+            assert_eq!(max.mul_add(&max, &max), max * max + max);
+            assert_eq!(max.mul_add(&$field::ZERO, &max), max);
+            assert_eq!($field::ZERO.mul_add(&$field::ZERO, &$field::ZERO), $field::ZERO);
+        }
+    };
+
     ($field: ident, "conversion") => {
         #[test]
         fn test_conversion() {
@@ -246,10 +268,13 @@ macro_rules! field_testing_suite {
                     let bytes = a.to_raw_bytes();
                     let b = $f::from_raw_bytes(&bytes).unwrap();
                     assert_eq!(a, b);
-                    let mut buf = Vec::new();
-                    a.write_raw(&mut buf).unwrap();
-                    let b = $f::read_raw(&mut &buf[..]).unwrap();
-                    assert_eq!(a, b);
+                    #[cfg(feature = "std")]
+                    {
+                        let mut buf = Vec::new();
+                        a.write_raw(&mut buf).unwrap();
+                        let b = $f::read_raw(&mut &buf[..]).unwrap();
+                        assert_eq!(a, b);
+                    }
                 }
             };
         }
@@ -280,7 +305,7 @@ macro_rules! field_testing_suite {
 
         #[test]
         fn test_serialization() {
-            use crate::serde::SerdeObject;
+            use $crate::serde::SerdeObject;
             random_serialization_test!($field);
             #[cfg(feature = "derive_serde")]
             random_serde_test!($field);
@@ -290,7 +315,7 @@ macro_rules! field_testing_suite {
     ($field: ident, "quadratic_residue") => {
         #[test]
         fn test_quadratic_residue() {
-            use crate::ff_ext::Legendre;
+            use $crate::ff_ext::Legendre;
             use ff::Field;
             use rand_core::SeedableRng;
             use rand_xorshift::XorShiftRng;
@@ -309,6 +334,35 @@ macro_rules! field_testing_suite {
         }
     };
 
+    ($field: ident, "is_square") => {
+        #[test]
+        fn test_is_square() {
+            use $crate::ff_ext::Legendre;
+            use ff::Field;
+            use rand_core::SeedableRng;
+            use rand_xorshift::XorShiftRng;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+            let mut squares = 0;
+            for _ in 0..5000 {
+                let elem = $field::random(&mut rng);
+                let is_square = bool::from(elem.is_square());
+                assert_eq!(is_square, bool::from(elem.sqrt().is_some()));
+                assert_eq!(is_square, bool::from(elem.ct_quadratic_residue()));
+                assert!(bool::from(elem.square().is_square()));
+                squares += is_square as usize;
+            }
+            // about half of the elements are squares
+            assert!((2000..3000).contains(&squares));
+
+            assert!(bool::from($field::ZERO.is_square()));
+            assert!(bool::from($field::ONE.is_square()));
+        }
+    };
+
     ($field: ident, "bits") => {
         #[test]
         #[cfg(feature = "bits")]
@@ -330,6 +384,581 @@ macro_rules! field_testing_suite {
         }
     };
 
+    ($field: ident, "ct_less_than_power_of_two") => {
+        #[test]
+        fn test_ct_less_than_power_of_two() {
+            use num_bigint::BigUint;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+            let one = BigUint::from(1u32);
+            let to_biguint = |a: &$field| BigUint::from_bytes_le(a.to_repr().as_ref());
+
+            for _ in 0..10000 {
+                // random element, shifted down to a random bit length
+                let bits = rng.next_u32() % ($field::NUM_BITS + 1);
+                let a = $field::random(&mut rng);
+                let a = $field::from_str_vartime(
+                    &(to_biguint(&a) >> ($field::NUM_BITS - bits)).to_str_radix(10),
+                )
+                .unwrap();
+                for bits in bits.saturating_sub(2)..=bits + 2 {
+                    assert_eq!(
+                        bool::from(a.ct_less_than_power_of_two(bits)),
+                        to_biguint(&a) < (&one << bits)
+                    );
+                }
+            }
+
+            // boundary values `2^bits - 1` and `2^bits`
+            for bits in 0..$field::NUM_BITS {
+                let pow = $field::from(2).pow_vartime([bits as u64]);
+                assert!(!bool::from(pow.ct_less_than_power_of_two(bits)));
+                assert!(bool::from(pow.ct_less_than_power_of_two(bits + 1)));
+                assert!(bool::from((pow - $field::ONE).ct_less_than_power_of_two(bits)));
+            }
+
+            let max = -$field::ONE;
+            assert!(!bool::from(max.ct_less_than_power_of_two($field::NUM_BITS - 1)));
+            assert!(bool::from(max.ct_less_than_power_of_two($field::NUM_BITS)));
+            assert!(bool::from(max.ct_less_than_power_of_two(1000)));
+            assert!(bool::from($field::ZERO.ct_less_than_power_of_two(0)));
+        }
+    };
+
+    ($field: ident, "from_repr_slice") => {
+        #[test]
+        fn test_from_repr_slice() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            for _ in 0..1000 {
+                let a = $field::random(&mut rng);
+                let repr = a.to_repr();
+                assert_eq!($field::from_repr_slice(repr.as_ref()).unwrap(), a);
+
+                // wrong length, even with a canonical prefix
+                let mut long = repr.as_ref().to_vec();
+                long.push(0);
+                assert!(bool::from($field::from_repr_slice(&long).is_none()));
+                assert!(bool::from($field::from_repr_slice(&repr.as_ref()[..31]).is_none()));
+            }
+            assert!(bool::from($field::from_repr_slice(&[]).is_none()));
+
+            // non-canonical: the modulus and all ones
+            let mut modulus = (-$field::ONE).to_repr();
+            modulus.as_mut()[0] += 1;
+            assert!(bool::from($field::from_repr_slice(modulus.as_ref()).is_none()));
+            assert!(bool::from($field::from_repr_slice(&[0xff; 32]).is_none()));
+        }
+    };
+
+    ($field: ident, "serialized_size") => {
+        #[test]
+        fn test_serialized_size() {
+            assert_eq!($field::SERIALIZED_SIZE, $field::size());
+            assert_eq!($field::SERIALIZED_SIZE, $field::ONE.to_repr().as_ref().len());
+            assert_eq!($field::SERIALIZED_SIZE, $field::ONE.to_bytes().len());
+
+            for n in [0, 1, 2, 100] {
+                let bytes = vec![$field::ONE; n]
+                    .iter()
+                    .flat_map(|e| e.to_bytes())
+                    .collect::<Vec<_>>();
+                assert_eq!($field::serialized_size_of(n), bytes.len());
+            }
+        }
+    };
+
+    ($field: ident, "mul_by_u64") => {
+        #[test]
+        fn test_mul_by_u64() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            for _ in 0..100 {
+                let a = $field::random(&mut rng);
+                assert_eq!(a.mul_by_3(), a * $field::from(3));
+                for k in (0..=64).chain([rng.next_u64(), u64::MAX]) {
+                    assert_eq!(a.mul_by_u64(k), a * $field::from(k));
+                }
+            }
+            assert_eq!($field::ZERO.mul_by_u64(u64::MAX), $field::ZERO);
+        }
+    };
+
+    ($field: ident, "conditional_select_slice") => {
+        #[test]
+        fn test_conditional_select_slice() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            for n in [0, 1, 10, 100] {
+                let a = (0..n).map(|_| $field::random(&mut rng)).collect::<Vec<_>>();
+                let b = (0..n).map(|_| $field::random(&mut rng)).collect::<Vec<_>>();
+                assert_eq!($field::conditional_select_slice(&a, &b, Choice::from(0u8)), a);
+                assert_eq!($field::conditional_select_slice(&a, &b, Choice::from(1u8)), b);
+            }
+        }
+    };
+
+    ($field: ident, "batch_invert") => {
+        #[test]
+        fn test_batch_invert() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            for n in [0, 1, 2, 10, 100] {
+                let mut elements = (0..n).map(|_| $field::random(&mut rng)).collect::<Vec<_>>();
+                for num_zeros in 0..=n.min(3) {
+                    // embed zeros at random positions
+                    for _ in 0..num_zeros {
+                        elements[rng.next_u32() as usize % n] = $field::ZERO;
+                    }
+
+                    let mut inverted = elements.clone();
+                    let product = $field::batch_invert(&mut inverted).unwrap();
+
+                    let mut expected_product = $field::ONE;
+                    for (element, inv) in elements.iter().zip(inverted.iter()) {
+                        if bool::from(element.is_zero()) {
+                            assert_eq!(*inv, $field::ZERO);
+                        } else {
+                            assert_eq!(*inv, element.invert().unwrap());
+                            expected_product *= inv;
+                        }
+                    }
+                    assert_eq!(product, expected_product);
+                }
+            }
+        }
+    };
+
+    ($field: ident, "pow_fixed_window") => {
+        #[test]
+        fn test_pow_fixed_window() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            for _ in 0..10 {
+                let a = $field::random(&mut rng);
+                for window_bits in [1, 2, 4, 5, 8] {
+                    assert_eq!(a.pow_fixed_window(&[], window_bits), $field::ONE);
+                    assert_eq!(a.pow_fixed_window(&[0, 0], window_bits), $field::ONE);
+                    assert_eq!(a.pow_fixed_window(&[1], window_bits), a);
+                    let exp = [
+                        rng.next_u64(),
+                        rng.next_u64(),
+                        rng.next_u64(),
+                        rng.next_u64() >> 2,
+                    ];
+                    assert_eq!(a.pow_fixed_window(&exp, window_bits), a.pow(exp));
+                }
+            }
+        }
+    };
+
+    ($field: ident, "try_from_bytes") => {
+        #[test]
+        fn test_try_from_bytes() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            for a in [$field::ZERO, $field::ONE, -$field::ONE]
+                .into_iter()
+                .chain((0..100).map(|_| $field::random(&mut rng)))
+            {
+                let bytes: [u8; 32] = a.into();
+                assert_eq!(bytes, a.to_repr());
+                assert_eq!($field::try_from(bytes), Ok(a));
+            }
+
+            // the modulus itself, as `-1 + 1` with the carry propagated by hand
+            let mut modulus: [u8; 32] = (-$field::ONE).into();
+            for byte in modulus.iter_mut() {
+                let (sum, carry) = byte.overflowing_add(1);
+                *byte = sum;
+                if !carry {
+                    break;
+                }
+            }
+            assert_eq!(
+                $field::try_from(modulus),
+                Err($crate::ff_ext::InvalidFieldElement)
+            );
+            assert_eq!(
+                $field::try_from([0xff; 32]),
+                Err($crate::ff_ext::InvalidFieldElement)
+            );
+        }
+    };
+
+    ($field: ident, "sqrt_alt") => {
+        #[test]
+        fn test_sqrt_alt() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            assert_eq!($field::ZERO.sqrt_alt().unwrap(), ($field::ZERO, $field::ZERO));
+            // 1 is odd, so the even root is p - 1
+            assert_eq!($field::ONE.sqrt_alt().unwrap(), (-$field::ONE, $field::ONE));
+
+            for _ in 0..1000 {
+                let a = $field::random(&mut rng);
+                let (r, neg_r) = a.square().sqrt_alt().unwrap();
+                assert!(r == a || r == -a);
+                assert_eq!(r.square(), a.square());
+                assert_eq!(neg_r.square(), a.square());
+                assert_eq!(neg_r, -r);
+                assert!(!bool::from(r.is_odd()));
+
+                let b = $field::random(&mut rng);
+                assert_eq!(bool::from(b.sqrt_alt().is_some()), bool::from(b.sqrt().is_some()));
+            }
+        }
+    };
+
+    ($field: ident, "sub_borrow") => {
+        #[test]
+        fn test_sub_borrow() {
+            use num_bigint::BigUint;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+            let to_biguint = |a: &$field| BigUint::from_bytes_le(a.to_repr().as_ref());
+
+            let edge = [$field::ZERO, $field::ONE, -$field::ONE];
+            for a in edge.iter() {
+                for b in edge.iter() {
+                    let (diff, borrow) = a.sub_borrow(b);
+                    assert_eq!(diff, a - b);
+                    assert_eq!(bool::from(borrow), to_biguint(a) < to_biguint(b));
+                }
+            }
+
+            for _ in 0..1000 {
+                let a = $field::random(&mut rng);
+                let b = $field::random(&mut rng);
+                let (diff, borrow) = a.sub_borrow(&b);
+                assert_eq!(diff, a - b);
+                assert_eq!(bool::from(borrow), to_biguint(&a) < to_biguint(&b));
+                assert!(!bool::from(a.sub_borrow(&a).1));
+            }
+        }
+    };
+
+    ($field: ident, "halve") => {
+        #[test]
+        fn test_halve() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            for a in [$field::ZERO, $field::ONE, -$field::ONE] {
+                assert_eq!(a.halve(), a * $field::TWO_INV);
+                assert_eq!(a.halve().double(), a);
+            }
+
+            for _ in 0..1000 {
+                let a = $field::random(&mut rng);
+                assert_eq!(a.halve(), a * $field::TWO_INV);
+                assert_eq!(a.halve().double(), a);
+            }
+        }
+    };
+
+    ($field: ident, "raw_vs_from") => {
+        #[test]
+        fn test_raw_vs_from() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            // `from_raw` takes canonical limbs and converts them to Montgomery form once
+            const RAW_ONE: $field = $field::from_raw([1, 0, 0, 0]);
+            assert_eq!(RAW_ONE, $field::ONE);
+
+            let ks = [0, 1, 2, 3, 255, 1 << 32, u64::MAX - 1, u64::MAX];
+            for k in ks.into_iter().chain((0..100).map(|_| rng.next_u64())) {
+                assert_eq!($field::from_raw([k, 0, 0, 0]), $field::from(k));
+            }
+        }
+    };
+
+    ($field: ident, "u64_assign_ops") => {
+        #[test]
+        fn test_u64_assign_ops() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            let mut a = $field::from(10);
+            a *= 3u64;
+            assert_eq!(a, $field::from(30));
+            a += 5u64;
+            assert_eq!(a, $field::from(35));
+            a -= 36u64;
+            assert_eq!(a, -$field::ONE);
+
+            for _ in 0..100 {
+                let a = $field::random(&mut rng);
+                let k = rng.next_u64();
+                let (mut b, mut c, mut d) = (a, a, a);
+                b += k;
+                c -= k;
+                d *= k;
+                assert_eq!(b, a + $field::from(k));
+                assert_eq!(c, a - $field::from(k));
+                assert_eq!(d, a * $field::from(k));
+            }
+        }
+    };
+
+    ($field: ident, "bytes_be") => {
+        #[test]
+        fn test_bytes_be() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            for _ in 0..1000 {
+                let a = $field::random(&mut rng);
+                let be = a.to_bytes_be();
+                assert_eq!($field::from_bytes_be(&be).unwrap(), a);
+
+                // exactly the reverse of the little-endian repr
+                let mut le = a.to_repr();
+                le.reverse();
+                assert_eq!(be, le);
+            }
+
+            let mut one = [0u8; 32];
+            one[31] = 1;
+            assert_eq!($field::ONE.to_bytes_be(), one);
+            assert_eq!($field::from_bytes_be(&one).unwrap(), $field::ONE);
+
+            // validated against the modulus like `from_repr`
+            let modulus: [u8; 32] = hex::decode(&$field::MODULUS[2..])
+                .unwrap()
+                .try_into()
+                .unwrap();
+            assert!(bool::from($field::from_bytes_be(&modulus).is_none()));
+            assert!(bool::from($field::from_bytes_be(&[0xff; 32]).is_none()));
+            assert_eq!((-$field::ONE).to_bytes_be(), {
+                let mut p_minus_one = modulus;
+                p_minus_one[31] -= 1;
+                p_minus_one
+            });
+        }
+    };
+
+    ($field: ident, "pow_ct") => {
+        #[test]
+        fn test_pow_ct() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            for _ in 0..100 {
+                let a = $field::random(&mut rng);
+                let exp = [(); 4].map(|_| rng.next_u64());
+                assert_eq!(a.pow_ct(&exp), a.pow_vartime(exp));
+
+                // short exponents give the same result as with `pow_vartime`
+                for exp in [[0; 4], [1, 0, 0, 0], [exp[0], 0, 0, 0], [u64::MAX; 4]] {
+                    assert_eq!(a.pow_ct(&exp), a.pow_vartime(exp));
+                }
+            }
+            assert_eq!($field::ZERO.pow_ct(&[0; 4]), $field::ONE);
+            assert_eq!($field::ZERO.pow_ct(&[5, 0, 0, 0]), $field::ZERO);
+        }
+    };
+
+    ($field: ident, "le_bits_array") => {
+        #[test]
+        fn test_le_bits_array() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            let edge = [$field::ZERO, $field::ONE, -$field::ONE];
+            for a in edge.into_iter().chain((0..100).map(|_| $field::random(&mut rng))) {
+                let bits = a.to_le_bits_array();
+                assert_eq!(bits.len(), $field::NUM_BITS as usize);
+
+                // same layout as `to_repr`, whose remaining bits are zero
+                let repr = a.to_repr();
+                for i in 0..repr.as_ref().len() * 8 {
+                    let bit = (repr.as_ref()[i / 8] >> (i % 8)) & 1 == 1;
+                    assert_eq!(bit, i < bits.len() && bits[i]);
+                }
+
+                #[cfg(feature = "bits")]
+                {
+                    use ff::PrimeFieldBits;
+                    let expected = a.to_le_bits();
+                    assert!(bits.iter().zip(expected.iter()).all(|(a, b)| *a == *b));
+                }
+            }
+            assert!($field::ONE.to_le_bits_array()[0]);
+            assert!($field::ZERO.to_le_bits_array().iter().all(|bit| !bit));
+        }
+    };
+
+    ($field: ident, "from_raw_checked") => {
+        // limbs of the modulus, as `-1 + 1`
+        fn modulus_limbs() -> [u64; 4] {
+            let repr = (-$field::ONE).to_repr();
+            let mut limbs = [0, 1, 2, 3]
+                .map(|i| u64::from_le_bytes(repr[i * 8..(i + 1) * 8].try_into().unwrap()));
+            for limb in limbs.iter_mut() {
+                let (sum, carry) = limb.overflowing_add(1);
+                *limb = sum;
+                if !carry {
+                    break;
+                }
+            }
+            limbs
+        }
+
+        #[test]
+        fn test_from_raw_checked() {
+            const ZERO: $field = $field::from_raw_checked([0, 0, 0, 0]);
+            const SEVEN: $field = $field::from_raw_checked([7, 0, 0, 0]);
+            assert_eq!(ZERO, $field::ZERO);
+            assert_eq!(SEVEN, $field::from(7));
+
+            let mut max = modulus_limbs();
+            max[0] -= 1;
+            assert_eq!($field::from_raw_checked(max), -$field::ONE);
+            assert_eq!($field::from_raw_checked(max), $field::from_raw(max));
+        }
+
+        #[test]
+        #[should_panic(expected = "input is not less than the modulus")]
+        fn test_from_raw_checked_modulus() {
+            $field::from_raw_checked(modulus_limbs());
+        }
+
+        #[test]
+        #[should_panic(expected = "input is not less than the modulus")]
+        fn test_from_raw_checked_max() {
+            $field::from_raw_checked([u64::MAX; 4]);
+        }
+    };
+    ($field: ident, "sqrt_canonical") => {
+        #[test]
+        fn test_sqrt_canonical() {
+            use num_bigint::BigUint;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+            let half = BigUint::parse_bytes($field::MODULUS.trim_start_matches("0x").as_bytes(), 16)
+                .unwrap()
+                >> 1;
+            let to_biguint = |a: &$field| BigUint::from_bytes_le(a.to_repr().as_ref());
+
+            assert_eq!($field::ZERO.sqrt_canonical().unwrap(), $field::ZERO);
+            assert_eq!($field::ONE.sqrt_canonical().unwrap(), $field::ONE);
+            assert!(!bool::from($field::ZERO.lexicographically_largest()));
+            assert!(bool::from((-$field::ONE).lexicographically_largest()));
+
+            for _ in 0..10000 {
+                let a = $field::random(&mut rng);
+                let root = a.square().sqrt_canonical().unwrap();
+                assert!(root == a || root == -a);
+                assert!(to_biguint(&root) <= half);
+                assert!(!bool::from(root.lexicographically_largest()));
+                assert_eq!(
+                    bool::from(a.lexicographically_largest()),
+                    to_biguint(&a) > half
+                );
+
+                if !bool::from(a.sqrt().is_some()) {
+                    assert!(bool::from(a.sqrt_canonical().is_none()));
+                }
+            }
+        }
+    };
+
+    ($field: ident, "bits_cross_width") => {
+        #[test]
+        #[cfg(feature = "bits")]
+        fn test_bits_cross_width() {
+            use ff::{FieldBits, PrimeFieldBits};
+
+            // Rebuild the limb layouts that `field_bits!` uses on 64-bit and
+            // 32-bit targets from the same `to_repr`, whichever target we run on.
+            fn limbs_64(bytes: &[u8]) -> [u64; 4] {
+                let mut limbs = [0u64; 4];
+                for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+                    *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+                }
+                limbs
+            }
+            fn limbs_32(bytes: &[u8]) -> [u32; 8] {
+                let mut limbs = [0u32; 8];
+                for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(4)) {
+                    *limb = u32::from_le_bytes(chunk.try_into().unwrap());
+                }
+                limbs
+            }
+            fn check(native: FieldBits<<$field as PrimeFieldBits>::ReprBits>, bytes: &[u8]) {
+                let bits_64 = FieldBits::new(limbs_64(bytes));
+                let bits_32 = FieldBits::new(limbs_32(bytes));
+                assert_eq!(native.len(), bits_64.len());
+                assert_eq!(native.len(), bits_32.len());
+                for idx in 0..native.len() {
+                    assert_eq!(native[idx], bits_64[idx]);
+                    assert_eq!(native[idx], bits_32[idx]);
+                }
+            }
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+            for _ in 0..1000000 {
+                let a = $field::random(&mut rng);
+                check(a.to_le_bits(), a.to_repr().as_ref());
+            }
+            for a in [$field::ZERO, $field::ONE, -$field::ONE] {
+                check(a.to_le_bits(), a.to_repr().as_ref());
+            }
+
+            // the modulus is odd, so it only differs from `-1` in the lowest bit
+            let mut modulus = (-$field::ONE).to_repr();
+            modulus.as_mut()[0] |= 1;
+            check($field::char_le_bits(), modulus.as_ref());
+        }
+    };
+
     ($field: ident, "serialization_check") => {
         fn is_less_than<const N: usize>(x: &[u64; N], y: &[u64; N]) -> bool {
             for i in (1..N).rev() {
@@ -344,7 +973,7 @@ macro_rules! field_testing_suite {
 
         #[test]
         fn test_serialization_check() {
-            use crate::serde::SerdeObject;
+            use $crate::serde::SerdeObject;
             let mut rng = XorShiftRng::from_seed([
                 0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
                 0xbc, 0xe5,
@@ -392,7 +1021,7 @@ macro_rules! field_testing_suite {
     ($field: ident, "sqrt") => {
         #[test]
         fn test_sqrt() {
-            use crate::ff_ext::Legendre;
+            use $crate::ff_ext::Legendre;
             use rand_core::OsRng;
 
             let v = ($field::TWO_INV).square().sqrt().unwrap();
@@ -449,6 +1078,46 @@ macro_rules! field_testing_suite {
         }
     };
 
+    ($field: ident, "from_uniform_bytes", $n: literal) => {
+        paste::paste! {
+            #[test]
+            fn [<test_from_uniform_bytes_ $n>]() {
+                use ff::FromUniformBytes;
+                use num_bigint::BigUint;
+
+                let modulus = BigUint::parse_bytes(
+                    $field::MODULUS.trim_start_matches("0x").as_bytes(),
+                    16,
+                )
+                .unwrap();
+                let reduce = |bytes: &[u8; $n]| {
+                    let expected = BigUint::from_bytes_le(bytes) % &modulus;
+                    $field::from_str_vartime(&expected.to_str_radix(10)).unwrap()
+                };
+
+                let mut rng = XorShiftRng::from_seed([
+                    0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                    0x06, 0xbc, 0xe5,
+                ]);
+                for _ in 0..10000 {
+                    let mut bytes = [0u8; $n];
+                    rng.fill_bytes(&mut bytes);
+                    assert_eq!(
+                        <$field as FromUniformBytes<$n>>::from_uniform_bytes(&bytes),
+                        reduce(&bytes)
+                    );
+                }
+
+                for bytes in [[0u8; $n], [0xff; $n]] {
+                    assert_eq!(
+                        <$field as FromUniformBytes<$n>>::from_uniform_bytes(&bytes),
+                        reduce(&bytes)
+                    );
+                }
+            }
+        }
+    };
+
     ($field: ident, "from_uniform_bytes", $test_vectors: expr) => {
         #[test]
         fn test_from_uniform_bytes() {
@@ -537,6 +1206,25 @@ macro_rules! field_testing_suite {
                 0
             );
         }
+
+        #[test]
+        fn test_f2_mul_by_base() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+                0xe5,
+            ]);
+
+            for _ in 0..1000 {
+                let a = $ext_field::random(&mut rng);
+                let s = $base_field::random(&mut rng);
+                let embedded = $ext_field {
+                    c0: s,
+                    c1: $base_field::zero(),
+                };
+
+                assert_eq!(a.mul_by_base(&s), a * embedded);
+            }
+        }
     };
 
     ($ext_field: ident, "f6_tests", $base_field: ident) => {
@@ -732,4 +1420,37 @@ macro_rules! field_testing_suite {
             }
         }
     };
+
+    ($ext_field: ident, "frobenius_coeffs", $frobenius_param: expr, $nonresidue: expr, $([$coeffs: expr, $num: expr, $den: expr]),+) => {
+        #[test]
+        fn test_frobenius_coeffs() {
+            use num_bigint::BigUint;
+
+            // p: modulus of base field
+            let p = BigUint::from_bytes_le(
+                &$frobenius_param
+                    .iter()
+                    .flat_map(|limb: &u64| limb.to_le_bytes())
+                    .collect::<Vec<_>>(),
+            );
+            let nonresidue = $nonresidue;
+
+            $(
+                // coeffs[i] = nonresidue^(num * (p^i - 1) / den)
+                for (i, coeff) in $coeffs.iter().enumerate() {
+                    let exp = (p.pow(i as u32) - 1u32) * BigUint::from($num as u32);
+                    assert_eq!(&exp % BigUint::from($den as u32), BigUint::from(0u32));
+                    let exp = exp / BigUint::from($den as u32);
+
+                    assert_eq!(
+                        *coeff,
+                        nonresidue.pow_vartime(exp.to_u64_digits()),
+                        "{}[{}]",
+                        stringify!($coeffs),
+                        i
+                    );
+                }
+            )+
+        }
+    };
 }